@@ -4,9 +4,16 @@ extern crate alloc;
 use alloc::vec::Vec;
 
 use rand::{Rng, SeedableRng};
-use rand_chacha::ChaCha8Rng;
+// ChaCha20Rng (not StdRng) because its stream is part of the `rand_chacha`
+// public API contract and stays stable across `rand`/`rand_chacha` releases,
+// unlike StdRng's underlying algorithm. The guest and host must derive the
+// exact same pixels from the same key, so the RNG choice can't silently drift.
+use rand_chacha::ChaCha20Rng;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+use k256::ecdsa::{Error as EcdsaError, SigningKey, VerifyingKey};
+use sha3::{Digest, Keccak256};
+
 /// Simple RGB pixel
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Pixel {
@@ -27,37 +34,38 @@ impl Pixel {
     }
 }
 
-/// Fixed-size image for ZK (32×64 half-canvas)
+/// Fixed-size image for ZK, parametric over the half-canvas width `W` and
+/// height `H`. `Image32x64` is the original 32×64 size used by the guest.
 #[derive(Clone, Serialize, Deserialize)]
-pub struct Image32x64 {
+pub struct Image<const W: usize, const H: usize> {
     // Using Vec instead of array for easier serialization
     // In ZK guest, this will be stack-allocated during generation
     pub pixels: Vec<Pixel>,
 }
 
-impl Image32x64 {
+impl<const W: usize, const H: usize> Image<W, H> {
     pub fn new(background: Pixel) -> Self {
         Self {
-            pixels: alloc::vec![background; 32 * 64],
+            pixels: alloc::vec![background; W * H],
         }
     }
 
     pub fn get_pixel(&self, x: u64, y: u64) -> Option<Pixel> {
-        if x < 32 && y < 64 {
-            Some(self.pixels[(y * 32 + x) as usize])
+        if (x as usize) < W && (y as usize) < H {
+            Some(self.pixels[y as usize * W + x as usize])
         } else {
             None
         }
     }
 
     pub fn set_pixel(&mut self, x: u64, y: u64, pixel: Pixel) {
-        if x < 32 && y < 64 {
-            self.pixels[(y * 32 + x) as usize] = pixel;
+        if (x as usize) < W && (y as usize) < H {
+            self.pixels[y as usize * W + x as usize] = pixel;
         }
     }
 
     pub fn to_bytes(&self) -> Vec<u8> {
-        let mut bytes = Vec::with_capacity(32 * 64 * 3);
+        let mut bytes = Vec::with_capacity(W * H * 3);
         for pixel in &self.pixels {
             bytes.push(pixel.r);
             bytes.push(pixel.g);
@@ -67,46 +75,63 @@ impl Image32x64 {
     }
 }
 
-/// Binary image for ZK proof (1 bit per pixel)
-/// Stores pixels as packed bits: 32×64 pixels = 2,048 bits = 256 bytes
-/// This is 24x smaller than RGB representation!
+pub type Image32x64 = Image<32, 64>;
+
+/// Binary image for ZK proof (1 bit per pixel), parametric over the
+/// half-canvas width `W` and height `H`. Packed length is `W*H` bits rounded
+/// up to the nearest byte; any unused bits in the final byte are masked off
+/// by `popcount`/`to_bytes` so they never leak into a commitment or a count.
 #[derive(Clone)]
-pub struct BinaryImage32x64 {
+pub struct BinaryImage<const W: usize, const H: usize> {
     // Back to Vec for simplicity - we'll use risc0's bytes encoding
     pub data: Vec<u8>,
 }
 
 // Manual Serialize/Deserialize to avoid Vec length prefix issues
-impl Serialize for BinaryImage32x64 {
+impl<const W: usize, const H: usize> Serialize for BinaryImage<W, H> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         // Serialize as a byte array to avoid variable length encoding
-        serializer.serialize_bytes(&self.data)
+        serializer.serialize_bytes(&self.to_bytes())
     }
 }
 
-impl<'de> Deserialize<'de> for BinaryImage32x64 {
+impl<'de, const W: usize, const H: usize> Deserialize<'de> for BinaryImage<W, H> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
     {
         let data = Vec::<u8>::deserialize(deserializer)?;
-        Ok(BinaryImage32x64 { data })
+        Ok(BinaryImage { data })
     }
 }
 
-impl BinaryImage32x64 {
+impl<const W: usize, const H: usize> BinaryImage<W, H> {
+    const BIT_LEN: usize = W * H;
+    const PACKED_LEN: usize = Self::BIT_LEN.div_ceil(8);
+
+    /// Mask for the valid bits in the final packed byte (`0xFF` when `W*H`
+    /// is an exact multiple of 8, i.e. no partial byte at all).
+    fn tail_mask() -> u8 {
+        let remaining = Self::BIT_LEN % 8;
+        if remaining == 0 {
+            0xFF
+        } else {
+            0xFFu8 << (8 - remaining)
+        }
+    }
+
     pub fn new() -> Self {
         Self {
-            data: alloc::vec![0u8; 256], // 32×64/8 = 256 bytes
+            data: alloc::vec![0u8; Self::PACKED_LEN],
         }
     }
 
     pub fn set_pixel(&mut self, x: u64, y: u64, value: bool) {
-        if x < 32 && y < 64 {
-            let bit_index = (y * 32 + x) as usize;
+        if (x as usize) < W && (y as usize) < H {
+            let bit_index = y as usize * W + x as usize;
             let byte_index = bit_index / 8;
             let bit_offset = bit_index % 8;
 
@@ -119,8 +144,8 @@ impl BinaryImage32x64 {
     }
 
     pub fn get_pixel(&self, x: u64, y: u64) -> bool {
-        if x < 32 && y < 64 {
-            let bit_index = (y * 32 + x) as usize;
+        if (x as usize) < W && (y as usize) < H {
+            let bit_index = y as usize * W + x as usize;
             let byte_index = bit_index / 8;
             let bit_offset = bit_index % 8;
 
@@ -130,8 +155,13 @@ impl BinaryImage32x64 {
         }
     }
 
+    /// Packed bytes with the unused tail bits (if any) masked to zero.
     pub fn to_bytes(&self) -> Vec<u8> {
-        self.data.clone()
+        let mut bytes = self.data.clone();
+        if let Some(last) = bytes.last_mut() {
+            *last &= Self::tail_mask();
+        }
+        bytes
     }
 
     pub fn from_bytes(bytes: &[u8]) -> Self {
@@ -139,6 +169,97 @@ impl BinaryImage32x64 {
             data: bytes.to_vec(),
         }
     }
+
+    // Bitwise kernels operate directly on the packed `data` field.
+
+    /// Per-pixel AND: the pixels both patterns agree are foreground.
+    pub fn and(&self, other: &Self) -> Self {
+        Self {
+            data: self.data.iter().zip(&other.data).map(|(a, b)| a & b).collect(),
+        }
+    }
+
+    /// Per-pixel OR: the pixels either pattern sets as foreground.
+    pub fn or(&self, other: &Self) -> Self {
+        Self {
+            data: self.data.iter().zip(&other.data).map(|(a, b)| a | b).collect(),
+        }
+    }
+
+    /// Per-pixel XOR: used to highlight where two patterns disagree, e.g.
+    /// diffing two private keys' images, or isolating the ink a single walk
+    /// added on top of the walks before it.
+    pub fn xor(&self, other: &Self) -> Self {
+        Self {
+            data: self.data.iter().zip(&other.data).map(|(a, b)| a ^ b).collect(),
+        }
+    }
+
+    /// Per-pixel NOT: flips every bit, including the padding past `W*H` in
+    /// the final byte - callers that care about exact bit counts should
+    /// compare through `popcount`/`to_bytes`, which mask that padding back out.
+    pub fn not(&self) -> Self {
+        Self {
+            data: self.data.iter().map(|b| !b).collect(),
+        }
+    }
+
+    /// Ink density: number of foreground bits set. Cheap enough for the
+    /// guest to commit alongside the pattern itself.
+    pub fn popcount(&self) -> u32 {
+        let masked = self.to_bytes();
+        masked.iter().map(|b| b.count_ones()).sum()
+    }
+
+    pub fn union_in_place(&mut self, other: &Self) {
+        for (a, b) in self.data.iter_mut().zip(&other.data) {
+            *a |= b;
+        }
+    }
+
+    pub fn intersect_in_place(&mut self, other: &Self) {
+        for (a, b) in self.data.iter_mut().zip(&other.data) {
+            *a &= b;
+        }
+    }
+}
+
+pub type BinaryImage32x64 = BinaryImage<32, 64>;
+
+/// i32-backed fixed-point number (6 decimal digits of precision), used so the
+/// walk's direction weights stay integer-deterministic end to end - no f32/f64
+/// in the guest, where float rounding isn't guaranteed bit-stable across
+/// targets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Fx32(pub i32);
+
+impl Fx32 {
+    pub const ONE: Fx32 = Fx32(1_000_000);
+    pub const ZERO: Fx32 = Fx32(0);
+}
+
+impl core::ops::Mul for Fx32 {
+    type Output = Fx32;
+
+    fn mul(self, other: Fx32) -> Fx32 {
+        Fx32(((i64::from(self.0) * i64::from(other.0)) / i64::from(Fx32::ONE.0)) as i32)
+    }
+}
+
+impl core::ops::Div for Fx32 {
+    type Output = Fx32;
+
+    fn div(self, other: Fx32) -> Fx32 {
+        Fx32(((i64::from(self.0) * i64::from(Fx32::ONE.0)) / i64::from(other.0)) as i32)
+    }
+}
+
+impl core::ops::Add for Fx32 {
+    type Output = Fx32;
+
+    fn add(self, other: Fx32) -> Fx32 {
+        Fx32(self.0 + other.0)
+    }
 }
 
 enum Direction {
@@ -146,6 +267,46 @@ enum Direction {
     Right,
     Up,
     Down,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+/// Which generator fills the half-canvas before mirroring. Lives alongside
+/// the generators themselves (rather than in the host CLI) so the zkVM guest
+/// can pick a fill mode too, not just the unproved host preview path.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FillMode {
+    /// Memoryless random walk (original behaviour)
+    Walk,
+    /// Gradient-noise fractal turbulence ink-blot
+    Turbulence,
+    /// Hilbert space-filling-curve coverage
+    Hilbert,
+}
+
+/// Derive the Ethereum address (last 20 bytes of `keccak256(uncompressed pubkey)`)
+/// for the secp256k1 key pair derived from `pk`. Runs inside the zkVM guest so
+/// the committed journal binds the address to the same secret that produced
+/// the pattern, and is exposed here so the host can cross-check it too.
+///
+/// Returns an error if `pk` isn't a valid secp256k1 scalar (zero, or >= the
+/// curve order) - a syntactically-valid 32-byte key isn't guaranteed to be
+/// one, so callers must handle this rather than assuming it always succeeds.
+pub fn derive_address(pk: &[u8; 32]) -> Result<[u8; 20], EcdsaError> {
+    let signing_key = SigningKey::from_bytes(pk.into())?;
+    let verifying_key = VerifyingKey::from(&signing_key);
+    let public_key_bytes = verifying_key.to_encoded_point(false);
+    let public_key_uncompressed = public_key_bytes.as_bytes();
+
+    let mut hasher = Keccak256::new();
+    hasher.update(&public_key_uncompressed[1..]); // Skip the 0x04 prefix
+    let hash = hasher.finalize();
+
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&hash[12..32]);
+    Ok(address)
 }
 
 /// Derive deterministic walk and step parameters from private key
@@ -159,171 +320,441 @@ pub fn derive_parameters(pk: &[u8; 32]) -> (u64, u64) {
     (walks, steps)
 }
 
-/// Generate Rorschach half-canvas (32×64) from private key
-/// This is the core deterministic function used in ZK proof
-///
-/// Uses virtual 64×64 coordinate system for drawing, but stores in 32×64 by mirroring
-/// coordinates at write time. This creates a cohesive centered pattern.
-pub fn generate_rorschach_half(
-    private_key: &[u8; 32],
-    walks: u64,
-    steps: u64,
-    foreground: Pixel,
-    background: Pixel,
-) -> Image32x64 {
-    const VIRTUAL_WIDTH: u64 = 64; // Virtual drawing space
-    const PHYSICAL_WIDTH: u64 = 32; // Physical storage width
-    const HEIGHT: u64 = 64;
+/// Move the cursor one step in virtual space, clamped to the margins. Shared
+/// by both generation paths so the eight-directional movement rules (diagonals
+/// step both axes at once) only live in one place.
+fn step_cursor(
+    direction: Direction,
+    cursor_x: &mut u64,
+    cursor_y: &mut u64,
+    left_margin: u64,
+    right_boundary: u64,
+    top_margin: u64,
+    bottom_margin: u64,
+) {
+    let mut step_x = |dx: i64| {
+        if dx < 0 {
+            if *cursor_x > left_margin {
+                *cursor_x -= 1;
+            }
+        } else if *cursor_x < right_boundary - 1 {
+            *cursor_x += 1;
+        }
+    };
+    let step_y = |cursor_y: &mut u64, dy: i64| {
+        if dy < 0 {
+            if *cursor_y > top_margin {
+                *cursor_y -= 1;
+            }
+        } else if *cursor_y < bottom_margin - 1 {
+            *cursor_y += 1;
+        }
+    };
 
-    let mut rng = ChaCha8Rng::from_seed(*private_key);
-    let mut image = Image32x64::new(background);
+    match direction {
+        Direction::Left => step_x(-1),
+        Direction::Right => step_x(1),
+        Direction::Up => step_y(cursor_y, -1),
+        Direction::Down => step_y(cursor_y, 1),
+        Direction::UpLeft => {
+            step_x(-1);
+            step_y(cursor_y, -1);
+        }
+        Direction::UpRight => {
+            step_x(1);
+            step_y(cursor_y, -1);
+        }
+        Direction::DownLeft => {
+            step_x(-1);
+            step_y(cursor_y, 1);
+        }
+        Direction::DownRight => {
+            step_x(1);
+            step_y(cursor_y, 1);
+        }
+    }
+}
 
-    // Generate centered pattern using virtual 64-wide coordinate system
-    for _ in 0..walks {
-        // Random starting position in center region (virtual coordinates)
-        let left_margin = VIRTUAL_WIDTH / 4; // 16
-        let right_boundary = 3 * VIRTUAL_WIDTH / 4; // 48
-        let top_margin = HEIGHT / 4; // 16
-        let bottom_margin = 3 * HEIGHT / 4; // 48
+/// `f32::floor` isn't available under `no_std` (no libcore float intrinsics),
+/// so the guest build routes through `libm` instead. Only `perlin_noise`
+/// needs this - everything else in the generators stays integer/`Fx32`.
+#[cfg(feature = "std")]
+fn floorf(x: f32) -> f32 {
+    x.floor()
+}
 
-        let mut cursor_x = rng.gen_range(left_margin..right_boundary);
-        let mut cursor_y = rng.gen_range(top_margin..bottom_margin);
+#[cfg(not(feature = "std"))]
+fn floorf(x: f32) -> f32 {
+    libm::floorf(x)
+}
 
-        // Draw starting pixel (with coordinate transformation)
-        let physical_x = if cursor_x >= PHYSICAL_WIDTH {
-            VIRTUAL_WIDTH - cursor_x - 1
-        } else {
-            cursor_x
-        };
-        image.set_pixel(physical_x, cursor_y, foreground);
+/// Fade curve `6t^5 - 15t^4 + 10t^3` used to smooth Perlin cell interpolation
+fn perlin_fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
 
-        // Random walk
-        for _ in 0..steps {
-            let direction = decide_direction_fixed(&mut rng, cursor_x, cursor_y);
+fn perlin_lerp(t: f32, a: f32, b: f32) -> f32 {
+    a + t * (b - a)
+}
 
-            // Move cursor (in virtual space)
-            match direction {
-                Direction::Left => {
-                    if cursor_x > left_margin {
-                        cursor_x -= 1;
-                    }
-                }
-                Direction::Right => {
-                    if cursor_x < right_boundary - 1 {
-                        cursor_x += 1;
-                    }
+/// Gradient dot-product for one of the 4 standard 2D gradient directions,
+/// selected by the low 2 bits of the permutation-table entry
+fn perlin_grad(hash: u8, x: f32, y: f32) -> f32 {
+    match hash & 3 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        _ => -x - y,
+    }
+}
+
+/// Classic Perlin gradient noise over a 256-entry permutation table
+fn perlin_noise(perm: &[u8; 256], x: f32, y: f32) -> f32 {
+    let xi = (floorf(x) as i32 & 255) as usize;
+    let yi = (floorf(y) as i32 & 255) as usize;
+    let xf = x - floorf(x);
+    let yf = y - floorf(y);
+
+    let u = perlin_fade(xf);
+    let v = perlin_fade(yf);
+
+    let perm_x = perm[xi] as usize;
+    let perm_x1 = perm[(xi + 1) & 255] as usize;
+
+    let aa = perm[(perm_x + yi) & 255];
+    let ab = perm[(perm_x + yi + 1) & 255];
+    let ba = perm[(perm_x1 + yi) & 255];
+    let bb = perm[(perm_x1 + yi + 1) & 255];
+
+    let x1 = perlin_lerp(u, perlin_grad(aa, xf, yf), perlin_grad(ba, xf - 1.0, yf));
+    let x2 = perlin_lerp(u, perlin_grad(ab, xf, yf - 1.0), perlin_grad(bb, xf - 1.0, yf - 1.0));
+    perlin_lerp(v, x1, x2)
+}
+
+/// Build a deterministic 256-entry permutation table via Fisher-Yates off
+/// the generator's own RNG, so the noise field stays seeded from the private
+/// key like everything else.
+fn build_permutation_table(rng: &mut ChaCha20Rng) -> [u8; 256] {
+    let mut perm = [0u8; 256];
+    for (i, slot) in perm.iter_mut().enumerate() {
+        *slot = i as u8;
+    }
+    for i in (1..256).rev() {
+        let j = rng.gen_range(0..=i);
+        perm.swap(i, j);
+    }
+    perm
+}
+
+/// Default octave count for `turbulence_mask`, used by callers (e.g. the
+/// zkVM guest, fixed at `FillMode::Walk`) that have no CLI flag of their own
+/// to plumb a choice through.
+pub const DEFAULT_TURBULENCE_OCTAVES: u32 = 4;
+/// Default per-pixel threshold for `turbulence_mask`; see
+/// `DEFAULT_TURBULENCE_OCTAVES`.
+pub const DEFAULT_TURBULENCE_THRESHOLD: f32 = 0.55;
+
+/// Fractal-turbulence ink mask: a fractal sum of `octaves` octaves of
+/// gradient noise, `abs`-folded each octave (the "turbulence" variant),
+/// thresholded per-pixel against `threshold` into a binary mask. Shared by
+/// the RGB and binary generators below so both pipelines see exactly the
+/// same blot.
+fn turbulence_mask<const W: usize, const H: usize>(rng: &mut ChaCha20Rng, octaves: u32, threshold: f32) -> BinaryImage<W, H> {
+    let perm = build_permutation_table(rng);
+    let mut mask = BinaryImage::new();
+
+    for y in 0..H as u64 {
+        for x in 0..W as u64 {
+            let mut freq = 1.0 / 16.0;
+            let mut amp = 1.0;
+            let mut sum = 0.0;
+            let mut max_sum = 0.0;
+
+            for _ in 0..octaves {
+                sum += perlin_noise(&perm, x as f32 * freq, y as f32 * freq).abs() * amp;
+                max_sum += amp;
+                freq *= 2.0;
+                amp *= 0.5;
+            }
+
+            let normalized = if max_sum > 0.0 { sum / max_sum } else { 0.0 };
+            if normalized > threshold {
+                mask.set_pixel(x, y, true);
+            }
+        }
+    }
+
+    mask
+}
+
+/// Smallest power of two `>= x`
+fn next_pow2(x: u64) -> u64 {
+    let mut n = 1;
+    while n < x {
+        n *= 2;
+    }
+    n
+}
+
+/// Decode Hilbert-curve distance `d` into `(x, y)` for a square of side `n = 2^k`
+fn hilbert_d2xy(n: u64, d: u64) -> (u64, u64) {
+    let mut x = 0u64;
+    let mut y = 0u64;
+    let mut t = d;
+
+    let mut s = 1;
+    while s < n {
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - x;
+                y = s - 1 - y;
+            }
+            core::mem::swap(&mut x, &mut y);
+        }
+
+        x += s * rx;
+        y += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+
+    (x, y)
+}
+
+/// Fill the center region along a Hilbert space-filling curve instead of a
+/// random walk, giving dense, evenly-connected coverage. `steps` caps how
+/// many of the `n^2` curve points get inked; `walks` seeds a random
+/// per-point skip so the fill still looks organic rather than a perfectly
+/// solid block.
+fn hilbert_mask<const W: usize, const H: usize>(rng: &mut ChaCha20Rng, walks: u64, steps: u64) -> BinaryImage<W, H> {
+    let left_margin = W as u64 / 4;
+    let region_width = W as u64 / 2;
+    let top_margin = H as u64 / 4;
+    let region_height = H as u64 / 2;
+
+    let n = next_pow2(region_width.max(region_height));
+    let point_count = (n * n).min(steps);
+    let skip_chance = walks;
+
+    let mut mask = BinaryImage::new();
+    for d in 0..point_count {
+        let (hx, hy) = hilbert_d2xy(n, d);
+        if hx >= region_width || hy >= region_height {
+            continue;
+        }
+
+        let skip = skip_chance > 0 && rng.gen_range(0..skip_chance + 4) == 0;
+        if !skip {
+            mask.set_pixel(left_margin + hx, top_margin + hy, true);
+        }
+    }
+
+    mask
+}
+
+/// Generate a Rorschach half-canvas from private key. `W`/`H` are the
+/// physical storage width/height; the walk is drawn in a virtual `2*W`-wide
+/// coordinate system and mirrored down to `W` at write time, which is what
+/// keeps the pattern centered regardless of canvas size.
+/// This is the core deterministic function used in ZK proof
+#[allow(clippy::too_many_arguments)]
+pub fn generate_rorschach_half<const W: usize, const H: usize>(
+    private_key: &[u8; 32],
+    walks: u64,
+    steps: u64,
+    foreground: Pixel,
+    background: Pixel,
+    horizontal_bias: Fx32,
+    vertical_bias: Fx32,
+    fill_mode: FillMode,
+    turbulence_octaves: u32,
+    turbulence_threshold: f32,
+) -> Image<W, H> {
+    let mut rng = ChaCha20Rng::from_seed(*private_key);
+    let mut image = Image::new(background);
+
+    match fill_mode {
+        FillMode::Walk => {
+            let virtual_width = (W * 2) as u64; // Virtual drawing space
+            let physical_width = W as u64; // Physical storage width
+            let height = H as u64;
+
+            // Generate centered pattern using the virtual 2*W-wide coordinate system
+            for _ in 0..walks {
+                // Random starting position in center region (virtual coordinates)
+                let left_margin = virtual_width / 4;
+                let right_boundary = 3 * virtual_width / 4;
+                let top_margin = height / 4;
+                let bottom_margin = 3 * height / 4;
+
+                let mut cursor_x = rng.gen_range(left_margin..right_boundary);
+                let mut cursor_y = rng.gen_range(top_margin..bottom_margin);
+
+                // Draw starting pixel (with coordinate transformation)
+                let physical_x = if cursor_x >= physical_width {
+                    virtual_width - cursor_x - 1
+                } else {
+                    cursor_x
+                };
+                image.set_pixel(physical_x, cursor_y, foreground);
+
+                // Random walk
+                for _ in 0..steps {
+                    let direction = decide_direction_fixed(&mut rng, cursor_x, cursor_y, virtual_width, height, horizontal_bias, vertical_bias);
+                    step_cursor(direction, &mut cursor_x, &mut cursor_y, left_margin, right_boundary, top_margin, bottom_margin);
+
+                    // Draw pixel (with coordinate transformation)
+                    let physical_x = if cursor_x >= physical_width {
+                        virtual_width - cursor_x - 1
+                    } else {
+                        cursor_x
+                    };
+                    image.set_pixel(physical_x, cursor_y, foreground);
                 }
-                Direction::Up => {
-                    if cursor_y > top_margin {
-                        cursor_y -= 1;
+            }
+        }
+        FillMode::Turbulence => {
+            let mask = turbulence_mask::<W, H>(&mut rng, turbulence_octaves, turbulence_threshold);
+            for y in 0..H as u64 {
+                for x in 0..W as u64 {
+                    if mask.get_pixel(x, y) {
+                        image.set_pixel(x, y, foreground);
                     }
                 }
-                Direction::Down => {
-                    if cursor_y < bottom_margin - 1 {
-                        cursor_y += 1;
+            }
+        }
+        FillMode::Hilbert => {
+            let mask = hilbert_mask::<W, H>(&mut rng, walks, steps);
+            for y in 0..H as u64 {
+                for x in 0..W as u64 {
+                    if mask.get_pixel(x, y) {
+                        image.set_pixel(x, y, foreground);
                     }
                 }
             }
-
-            // Draw pixel (with coordinate transformation)
-            let physical_x = if cursor_x >= PHYSICAL_WIDTH {
-                VIRTUAL_WIDTH - cursor_x - 1
-            } else {
-                cursor_x
-            };
-            image.set_pixel(physical_x, cursor_y, foreground);
         }
     }
 
     image
 }
 
-/// Generate binary Rorschach pattern (for ZK proof)
-/// Returns only which pixels are foreground (true) vs background (false)
-/// This is 24x more efficient than RGB for ZK circuits!
-pub fn generate_rorschach_binary(
+/// Generate each walk of the binary Rorschach pattern into its own fresh
+/// layer, instead of drawing them all onto one shared canvas. Lets callers
+/// build per-walk difference images, or XOR two keys' patterns for visual
+/// diffing, without any RGB expansion.
+#[allow(clippy::too_many_arguments)]
+pub fn generate_rorschach_binary_layers<const W: usize, const H: usize>(
     private_key: &[u8; 32],
     walks: u64,
     steps: u64,
-) -> BinaryImage32x64 {
-    const VIRTUAL_WIDTH: u64 = 64; // Virtual drawing space
-    const PHYSICAL_WIDTH: u64 = 32; // Physical storage width
-    const HEIGHT: u64 = 64;
+    horizontal_bias: Fx32,
+    vertical_bias: Fx32,
+    fill_mode: FillMode,
+    turbulence_octaves: u32,
+    turbulence_threshold: f32,
+) -> Vec<BinaryImage<W, H>> {
+    let mut rng = ChaCha20Rng::from_seed(*private_key);
+
+    match fill_mode {
+        // Turbulence and Hilbert have no per-walk structure to layer - each
+        // is one pass over the canvas, so it's a single "layer" that
+        // `generate_rorschach_binary` unions against nothing else.
+        FillMode::Turbulence => return alloc::vec![turbulence_mask::<W, H>(&mut rng, turbulence_octaves, turbulence_threshold)],
+        FillMode::Hilbert => return alloc::vec![hilbert_mask::<W, H>(&mut rng, walks, steps)],
+        FillMode::Walk => {}
+    }
 
-    let mut rng = ChaCha8Rng::from_seed(*private_key);
-    let mut image = BinaryImage32x64::new(); // All pixels start as false (background)
+    let virtual_width = (W * 2) as u64; // Virtual drawing space
+    let physical_width = W as u64; // Physical storage width
+    let height = H as u64;
 
-    // Generate centered pattern using virtual 64-wide coordinate system
+    let mut layers = Vec::with_capacity(walks as usize);
+
+    // Generate centered pattern using the virtual 2*W-wide coordinate system
     for _ in 0..walks {
+        let mut layer = BinaryImage::new();
+
         // Random starting position in center region (virtual coordinates)
-        let left_margin = VIRTUAL_WIDTH / 4; // 16
-        let right_boundary = 3 * VIRTUAL_WIDTH / 4; // 48
-        let top_margin = HEIGHT / 4; // 16
-        let bottom_margin = 3 * HEIGHT / 4; // 48
+        let left_margin = virtual_width / 4;
+        let right_boundary = 3 * virtual_width / 4;
+        let top_margin = height / 4;
+        let bottom_margin = 3 * height / 4;
 
         let mut cursor_x = rng.gen_range(left_margin..right_boundary);
         let mut cursor_y = rng.gen_range(top_margin..bottom_margin);
 
         // Draw starting pixel (with coordinate transformation)
-        let physical_x = if cursor_x >= PHYSICAL_WIDTH {
-            VIRTUAL_WIDTH - cursor_x - 1
+        let physical_x = if cursor_x >= physical_width {
+            virtual_width - cursor_x - 1
         } else {
             cursor_x
         };
-        image.set_pixel(physical_x, cursor_y, true); // true = foreground
+        layer.set_pixel(physical_x, cursor_y, true); // true = foreground
 
         // Random walk
         for _ in 0..steps {
-            let direction = decide_direction_fixed(&mut rng, cursor_x, cursor_y);
-
-            // Move cursor (in virtual space)
-            match direction {
-                Direction::Left => {
-                    if cursor_x > left_margin {
-                        cursor_x -= 1;
-                    }
-                }
-                Direction::Right => {
-                    if cursor_x < right_boundary - 1 {
-                        cursor_x += 1;
-                    }
-                }
-                Direction::Up => {
-                    if cursor_y > top_margin {
-                        cursor_y -= 1;
-                    }
-                }
-                Direction::Down => {
-                    if cursor_y < bottom_margin - 1 {
-                        cursor_y += 1;
-                    }
-                }
-            }
+            let direction = decide_direction_fixed(&mut rng, cursor_x, cursor_y, virtual_width, height, horizontal_bias, vertical_bias);
+            step_cursor(direction, &mut cursor_x, &mut cursor_y, left_margin, right_boundary, top_margin, bottom_margin);
 
             // Draw pixel (with coordinate transformation)
-            let physical_x = if cursor_x >= PHYSICAL_WIDTH {
-                VIRTUAL_WIDTH - cursor_x - 1
+            let physical_x = if cursor_x >= physical_width {
+                virtual_width - cursor_x - 1
             } else {
                 cursor_x
             };
-            image.set_pixel(physical_x, cursor_y, true);
+            layer.set_pixel(physical_x, cursor_y, true);
         }
+
+        layers.push(layer);
     }
 
+    layers
+}
+
+/// Generate binary Rorschach pattern (for ZK proof)
+/// Returns only which pixels are foreground (true) vs background (false)
+/// This is 24x more efficient than RGB for ZK circuits!
+#[allow(clippy::too_many_arguments)]
+pub fn generate_rorschach_binary<const W: usize, const H: usize>(
+    private_key: &[u8; 32],
+    walks: u64,
+    steps: u64,
+    horizontal_bias: Fx32,
+    vertical_bias: Fx32,
+    fill_mode: FillMode,
+    turbulence_octaves: u32,
+    turbulence_threshold: f32,
+) -> BinaryImage<W, H> {
+    let mut image = BinaryImage::new();
+    for layer in &generate_rorschach_binary_layers::<W, H>(
+        private_key,
+        walks,
+        steps,
+        horizontal_bias,
+        vertical_bias,
+        fill_mode,
+        turbulence_octaves,
+        turbulence_threshold,
+    ) {
+        image.union_in_place(layer);
+    }
     image
 }
 
 /// Convert binary image to RGB with specified colors
 /// This is done on the host side after proof generation
-pub fn binary_to_rgb(
-    binary: &BinaryImage32x64,
+pub fn binary_to_rgb<const W: usize, const H: usize>(
+    binary: &BinaryImage<W, H>,
     foreground: Pixel,
     background: Pixel,
-) -> Image32x64 {
-    let mut image = Image32x64::new(background);
+) -> Image<W, H> {
+    let mut image = Image::new(background);
 
-    for y in 0..64 {
-        for x in 0..32 {
+    for y in 0..H as u64 {
+        for x in 0..W as u64 {
             if binary.get_pixel(x, y) {
                 image.set_pixel(x, y, foreground);
             }
@@ -333,60 +764,122 @@ pub fn binary_to_rgb(
     image
 }
 
-/// Deterministic direction decision using fixed-point arithmetic (no f32)
-/// Uses u32 instead of f32 for ZK efficiency
-/// Now uses VIRTUAL_WIDTH for probability calculations to work with virtual coordinate system
-fn decide_direction_fixed(rng: &mut ChaCha8Rng, cursor_x: u64, cursor_y: u64) -> Direction {
-    const VIRTUAL_WIDTH: u64 = 64; // Virtual coordinate space
-    const HEIGHT: u64 = 64;
-    const SCALE: u32 = 1_000_000; // Fixed-point scale
-
-    let left_margin = VIRTUAL_WIDTH / 4; // 16
-    let right_boundary = 3 * VIRTUAL_WIDTH / 4; // 48
-    let top_margin = HEIGHT / 4; // 16
-    let bottom_boundary = 3 * HEIGHT / 4; // 48
-
-    // Calculate probabilities as fixed-point u32 (scaled by 1,000,000)
-    // Use VIRTUAL_WIDTH for distance calculations
-    let distance_from_left_margin = cursor_x.saturating_sub(left_margin);
-    let left_prob = if distance_from_left_margin >= VIRTUAL_WIDTH / 4 {
-        SCALE
-    } else {
-        ((distance_from_left_margin * SCALE as u64) / (VIRTUAL_WIDTH / 4)) as u32
-    };
-
-    let distance_from_right = right_boundary.saturating_sub(cursor_x);
-    let right_prob = if distance_from_right >= VIRTUAL_WIDTH / 4 {
-        SCALE
+/// Margin-distance falloff as an `Fx32` weight: pixels within `margin` of the
+/// boundary get pulled back in proportion to how close they are to it, and
+/// pixels further in are free (full weight).
+fn margin_weight(distance: u64, margin: u64) -> Fx32 {
+    if distance >= margin {
+        Fx32::ONE
     } else {
-        ((distance_from_right * SCALE as u64) / (VIRTUAL_WIDTH / 4)) as u32
-    };
+        Fx32(((distance * Fx32::ONE.0 as u64) / margin) as i32)
+    }
+}
 
-    let distance_from_top = cursor_y.saturating_sub(top_margin);
-    let up_prob = if distance_from_top >= HEIGHT / 4 {
-        SCALE
-    } else {
-        ((distance_from_top * SCALE as u64) / (HEIGHT / 4)) as u32
-    };
+/// Deterministic direction decision using `Fx32` fixed-point arithmetic (no
+/// floats). Samples one of eight neighbors - the four cardinals plus their
+/// diagonals - weighted by the same margin-distance falloff as before, with
+/// `horizontal_bias`/`vertical_bias` stretching the x/y weights so callers can
+/// tune the walk's morphology (e.g. a taller or wider blot) while staying
+/// integer-deterministic for the ZK guest.
+#[allow(clippy::too_many_arguments)]
+fn decide_direction_fixed(
+    rng: &mut ChaCha20Rng,
+    cursor_x: u64,
+    cursor_y: u64,
+    virtual_width: u64,
+    height: u64,
+    horizontal_bias: Fx32,
+    vertical_bias: Fx32,
+) -> Direction {
+    let left_margin = virtual_width / 4;
+    let right_boundary = 3 * virtual_width / 4;
+    let top_margin = height / 4;
+    let bottom_boundary = 3 * height / 4;
+
+    let left_w = margin_weight(cursor_x.saturating_sub(left_margin), virtual_width / 4) * horizontal_bias;
+    let right_w = margin_weight(right_boundary.saturating_sub(cursor_x), virtual_width / 4) * horizontal_bias;
+    let up_w = margin_weight(cursor_y.saturating_sub(top_margin), height / 4) * vertical_bias;
+    let down_w = margin_weight(bottom_boundary.saturating_sub(cursor_y), height / 4) * vertical_bias;
+
+    // Each diagonal's weight is the average of its two orthogonal neighbors,
+    // so corners are sampled neither more nor less often than the cardinals
+    // they sit between.
+    let half = Fx32(Fx32::ONE.0 / 2);
+    let up_left_w = (up_w + left_w) * half;
+    let up_right_w = (up_w + right_w) * half;
+    let down_left_w = (down_w + left_w) * half;
+    let down_right_w = (down_w + right_w) * half;
+
+    // Every weight is floored at 1 so a direction zeroed out by bias still has
+    // a sliver of a chance, and the cumulative sum below never stalls at 0.
+    let weights = [
+        (Direction::Left, left_w.0.max(1)),
+        (Direction::Right, right_w.0.max(1)),
+        (Direction::Up, up_w.0.max(1)),
+        (Direction::Down, down_w.0.max(1)),
+        (Direction::UpLeft, up_left_w.0.max(1)),
+        (Direction::UpRight, up_right_w.0.max(1)),
+        (Direction::DownLeft, down_left_w.0.max(1)),
+        (Direction::DownRight, down_right_w.0.max(1)),
+    ];
+
+    let total: i64 = weights.iter().map(|(_, w)| i64::from(*w)).sum();
+    let mut sample = (rng.gen::<u32>() as i64) % total;
+
+    for (direction, weight) in weights {
+        if sample < i64::from(weight) {
+            return direction;
+        }
+        sample -= i64::from(weight);
+    }
 
-    let distance_from_bottom = bottom_boundary.saturating_sub(cursor_y);
-    let down_prob = if distance_from_bottom >= HEIGHT / 4 {
-        SCALE
-    } else {
-        ((distance_from_bottom * SCALE as u64) / (HEIGHT / 4)) as u32
-    };
+    unreachable!("cumulative weights must cover the full sampled range")
+}
 
-    let total = left_prob + right_prob + up_prob + down_prob;
-    let rand_val = rng.gen::<u32>() % total;
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Pins `generate_rorschach_half` to a fixed `ChaCha20Rng` stream so a
+    /// future `rand`/`rand_chacha` upgrade that silently changes the stream
+    /// (and therefore desyncs host and guest) fails loudly here instead of
+    /// only showing up as a `--check` mismatch in the field.
+    ///
+    /// Uses a small 4x4 canvas (rather than the default 32x64) so the golden
+    /// vector stays short enough to read and re-derive by hand. Regenerate
+    /// the expected bytes by printing `image.to_bytes()` with the same
+    /// key/walks/steps if this ever needs to change on purpose.
+    #[test]
+    fn generate_rorschach_half_is_reproducible_for_a_fixed_key() {
+        let mut private_key = [0u8; 32];
+        for (i, byte) in private_key.iter_mut().enumerate() {
+            *byte = (i + 1) as u8;
+        }
 
-    // Cumulative distribution
-    if rand_val < left_prob {
-        Direction::Left
-    } else if rand_val < left_prob + right_prob {
-        Direction::Right
-    } else if rand_val < left_prob + right_prob + up_prob {
-        Direction::Up
-    } else {
-        Direction::Down
+        let foreground = Pixel::new(1, 2, 3);
+        let background = Pixel::new(4, 5, 6);
+
+        let image = generate_rorschach_half::<4, 4>(
+            &private_key,
+            1,
+            1,
+            foreground,
+            background,
+            Fx32::ONE,
+            Fx32::ONE,
+            FillMode::Walk,
+            DEFAULT_TURBULENCE_OCTAVES,
+            DEFAULT_TURBULENCE_THRESHOLD,
+        );
+
+        #[rustfmt::skip]
+        let expected: [u8; 48] = [
+            4, 5, 6,  4, 5, 6,  4, 5, 6,  4, 5, 6,
+            4, 5, 6,  4, 5, 6,  4, 5, 6,  4, 5, 6,
+            4, 5, 6,  4, 5, 6,  1, 2, 3,  1, 2, 3,
+            4, 5, 6,  4, 5, 6,  4, 5, 6,  4, 5, 6,
+        ];
+
+        assert_eq!(image.to_bytes(), expected);
     }
 }
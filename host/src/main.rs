@@ -4,11 +4,240 @@ use alloy::signers::local::PrivateKeySigner;
 use clap::Parser;
 use image::{ImageBuffer, Rgb};
 use risc0_zkvm::{default_prover, ExecutorEnv, Receipt};
-use ror_core::{binary_to_rgb, derive_parameters, generate_rorschach_half, BinaryImage32x64, Image32x64, Pixel};
+use ror_core::{
+    binary_to_rgb, derive_address, derive_parameters, generate_rorschach_binary, generate_rorschach_binary_layers,
+    generate_rorschach_half, BinaryImage, BinaryImage32x64, FillMode, Fx32, Image, Pixel,
+};
 
 // Include the generated guest code
 use methods::{GUEST_ELF, GUEST_ID};
 
+/// Output format selected with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    /// Nearest-neighbor upscaled raster (the original behaviour)
+    Png,
+    /// Resolution-independent vector output
+    Svg,
+    /// Plain-text terminal preview
+    Ascii,
+}
+
+/// Fill mode selected with `--fill-mode`. Mirrors `ror_core::FillMode` - kept
+/// as a separate type because `ror_core` stays `clap`-free for the guest's
+/// sake (see `with_canvas_size!`/`CanvasSize` for the same pattern).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum FillModeArg {
+    /// Memoryless random walk (original behaviour)
+    Walk,
+    /// Gradient-noise fractal turbulence ink-blot
+    Turbulence,
+    /// Hilbert space-filling-curve coverage
+    Hilbert,
+}
+
+impl From<FillModeArg> for FillMode {
+    fn from(value: FillModeArg) -> Self {
+        match value {
+            FillModeArg::Walk => FillMode::Walk,
+            FillModeArg::Turbulence => FillMode::Turbulence,
+            FillModeArg::Hilbert => FillMode::Hilbert,
+        }
+    }
+}
+
+/// A rasterizer the mirrored full canvas can be rendered through, so the
+/// generator stays decoupled from how the result is written to disk. Adding
+/// another output format is a matter of one more impl.
+trait RenderBackend {
+    fn begin(&mut self, width: u32, height: u32);
+    fn put_pixel(&mut self, x: u32, y: u32, pixel: Pixel);
+    fn finish(&self, path: &PathBuf) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+/// Nearest-neighbor upscale into a raster PNG, same as the original pipeline
+struct PngBackend {
+    width: u32,
+    height: u32,
+    scale: u32,
+    pixels: Vec<Pixel>,
+}
+
+impl PngBackend {
+    fn new(scale: u32) -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            scale,
+            pixels: Vec::new(),
+        }
+    }
+}
+
+impl RenderBackend for PngBackend {
+    fn begin(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+        self.pixels = vec![Pixel::new(255, 255, 255); (width * height) as usize];
+    }
+
+    fn put_pixel(&mut self, x: u32, y: u32, pixel: Pixel) {
+        if x < self.width && y < self.height {
+            self.pixels[(y * self.width + x) as usize] = pixel;
+        }
+    }
+
+    fn finish(&self, path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+        let mut image = ImageBuffer::<Rgb<u8>, Vec<u8>>::new(self.width * self.scale, self.height * self.scale);
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let rgb = Rgb(self.pixels[(y * self.width + x) as usize].to_rgb_array());
+                for dy in 0..self.scale {
+                    for dx in 0..self.scale {
+                        image.put_pixel(x * self.scale + dx, y * self.scale + dy, rgb);
+                    }
+                }
+            }
+        }
+
+        image.save(path)?;
+        Ok(())
+    }
+}
+
+/// Vector output: one `<rect>` per colored cell, merging horizontal runs of
+/// identical color into a single wide rect to keep file size reasonable.
+struct SvgBackend {
+    width: u32,
+    height: u32,
+    cell_size: u32,
+    pixels: Vec<Pixel>,
+}
+
+impl SvgBackend {
+    fn new(cell_size: u32) -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            cell_size,
+            pixels: Vec::new(),
+        }
+    }
+}
+
+impl RenderBackend for SvgBackend {
+    fn begin(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+        self.pixels = vec![Pixel::new(255, 255, 255); (width * height) as usize];
+    }
+
+    fn put_pixel(&mut self, x: u32, y: u32, pixel: Pixel) {
+        if x < self.width && y < self.height {
+            self.pixels[(y * self.width + x) as usize] = pixel;
+        }
+    }
+
+    fn finish(&self, path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+        let px_width = self.width * self.cell_size;
+        let px_height = self.height * self.cell_size;
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+            px_width, px_height, px_width, px_height,
+        );
+
+        for y in 0..self.height {
+            let mut x = 0;
+            while x < self.width {
+                let color = self.pixels[(y * self.width + x) as usize];
+                let mut run_end = x + 1;
+                while run_end < self.width && self.pixels[(y * self.width + run_end) as usize] == color {
+                    run_end += 1;
+                }
+                let run_width = (run_end - x) * self.cell_size;
+
+                svg.push_str(&format!(
+                    "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{:02x}{:02x}{:02x}\"/>\n",
+                    x * self.cell_size,
+                    y * self.cell_size,
+                    run_width,
+                    self.cell_size,
+                    color.r,
+                    color.g,
+                    color.b,
+                ));
+
+                x = run_end;
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        fs::write(path, svg)?;
+        Ok(())
+    }
+}
+
+/// Plain-text terminal preview: one character per pixel, blank for whatever
+/// was passed as the background color.
+struct AsciiBackend {
+    width: u32,
+    height: u32,
+    background: Pixel,
+    pixels: Vec<Pixel>,
+}
+
+impl AsciiBackend {
+    fn new(background: Pixel) -> Self {
+        Self {
+            width: 0,
+            height: 0,
+            background,
+            pixels: Vec::new(),
+        }
+    }
+}
+
+impl RenderBackend for AsciiBackend {
+    fn begin(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+        self.pixels = vec![self.background; (width * height) as usize];
+    }
+
+    fn put_pixel(&mut self, x: u32, y: u32, pixel: Pixel) {
+        if x < self.width && y < self.height {
+            self.pixels[(y * self.width + x) as usize] = pixel;
+        }
+    }
+
+    fn finish(&self, path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+        let mut text = String::with_capacity(((self.width + 1) * self.height) as usize);
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                let pixel = self.pixels[(y * self.width + x) as usize];
+                text.push(if pixel == self.background { ' ' } else { '#' });
+            }
+            text.push('\n');
+        }
+
+        fs::write(path, text)?;
+        Ok(())
+    }
+}
+
+fn render(backend: &mut dyn RenderBackend, pixels: &[Pixel], width: u32, height: u32, path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+    backend.begin(width, height);
+    for y in 0..height {
+        for x in 0..width {
+            backend.put_pixel(x, y, pixels[(y * width + x) as usize]);
+        }
+    }
+    backend.finish(path)
+}
+
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 struct Cli {
@@ -57,6 +286,71 @@ struct Cli {
     /// Verify an existing proof
     #[arg(long)]
     verify: Option<PathBuf>,
+
+    /// Output format: raster PNG, vector SVG, or an ASCII terminal preview
+    #[arg(long, value_enum, default_value = "png")]
+    format: OutputFormat,
+
+    /// PNG: pixel upscale factor. SVG: cell size in output units. Ignored by ASCII.
+    #[arg(long, default_value = "8")]
+    scale: u32,
+
+    /// Re-derive the pattern from --private-key and check it against the
+    /// commitment chunk embedded in this PNG (no .proof file required)
+    #[arg(long)]
+    check: Option<PathBuf>,
+
+    /// Half-canvas size as `WxH` (e.g. the default `32x64`, or `64x128` for a
+    /// larger blot). Rejected with `--prove`/`--check` unless left at the
+    /// default - the zkVM guest's canvas is a fixed compile-time size, so a
+    /// proved/checked image is always 32x64.
+    #[arg(long, default_value = "32x64")]
+    size: CanvasSize,
+
+    /// Horizontal stretch applied to the walk's left/right weights (1.0 = unbiased)
+    #[arg(long, default_value = "1.0")]
+    bias_x: f64,
+
+    /// Vertical stretch applied to the walk's up/down weights (1.0 = unbiased)
+    #[arg(long, default_value = "1.0")]
+    bias_y: f64,
+
+    /// Which generator fills the half-canvas before mirroring. Rejected with
+    /// `--prove`/`--check` unless left at `walk` - the zkVM guest's fill mode
+    /// is fixed at compile time, so a proved image is always `walk`, and
+    /// `--check` takes the fill mode from the embedded commitment instead.
+    #[arg(long, value_enum, default_value = "walk")]
+    fill_mode: FillModeArg,
+
+    /// Render the XOR of this key's pattern against --private-key instead of
+    /// the pattern itself, for visually diffing two keys. Rejected with
+    /// `--prove`/`--check`.
+    #[arg(long)]
+    diff_key: Option<String>,
+
+    /// Number of fractal-turbulence octaves (only used by --fill-mode turbulence)
+    #[arg(long, default_value = "4")]
+    octaves: u32,
+
+    /// Normalized turbulence cutoff in [0,1]; above it the foreground pixel is set
+    #[arg(long, default_value = "0.55")]
+    turbulence_threshold: f32,
+}
+
+/// Convert a user-facing bias factor to the `Fx32` the generator expects.
+/// Only called where the `--bias-x`/`--bias-y` flags are read - everything
+/// past this point is plain integer arithmetic.
+fn bias_from_f64(value: f64) -> Fx32 {
+    Fx32((value * Fx32::ONE.0 as f64).round() as i32)
+}
+
+/// Parse a hex-encoded 32-byte private key, with or without a `0x` prefix.
+/// Shared by `--private-key` and `--diff-key`.
+fn parse_private_key(hex_str: &str) -> Result<[u8; 32], Box<dyn std::error::Error>> {
+    let hex_str = hex_str.trim_start_matches("0x");
+    Ok(hex::decode(hex_str)?
+        .try_into()
+        .map_err(|_| "Private key must be exactly 32 bytes")?)
 }
 
 #[derive(Debug, Clone)]
@@ -79,15 +373,71 @@ impl FromStr for RgbX {
 }
 
 impl RgbX {
-    fn to_rgb(&self) -> Rgb<u8> {
-        Rgb([self.0, self.1, self.2])
-    }
-
     fn to_pixel(&self) -> Pixel {
         Pixel::new(self.0, self.1, self.2)
     }
 }
 
+/// Half-canvas width/height requested with `--size`, parsed from `WxH`.
+/// Resolving this to the `Image<W, H>`/`BinaryImage<W, H>` const generics
+/// happens in `with_canvas_size!` below, against a fixed menu of supported
+/// sizes - `W`/`H` have to be known at compile time, so a runtime value here
+/// can only select among existing monomorphizations, not conjure a new one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct CanvasSize(u32, u32);
+
+impl FromStr for CanvasSize {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (w, h) = s.split_once('x').ok_or("size must be WxH, e.g. 64x128")?;
+        let w: u32 = w.parse().map_err(|_| "invalid width in --size".to_string())?;
+        let h: u32 = h.parse().map_err(|_| "invalid height in --size".to_string())?;
+        Ok(CanvasSize(w, h))
+    }
+}
+
+impl CanvasSize {
+    const DEFAULT: CanvasSize = CanvasSize(32, 64);
+}
+
+/// Runs `$body` with `$w`/`$h` bound as `const usize` matching `$size`,
+/// picking from the fixed menu of supported canvas sizes and returning a
+/// descriptive error for anything else.
+macro_rules! with_canvas_size {
+    ($size:expr, |$w:ident, $h:ident| $body:expr) => {
+        match ($size.0, $size.1) {
+            (32, 64) => {
+                const $w: usize = 32;
+                const $h: usize = 64;
+                $body
+            }
+            (16, 32) => {
+                const $w: usize = 16;
+                const $h: usize = 32;
+                $body
+            }
+            (64, 64) => {
+                const $w: usize = 64;
+                const $h: usize = 64;
+                $body
+            }
+            (64, 128) => {
+                const $w: usize = 64;
+                const $h: usize = 128;
+                $body
+            }
+            (w, h) => {
+                return Err(format!(
+                    "unsupported --size {}x{} (supported: 32x64, 16x32, 64x64, 64x128)",
+                    w, h
+                )
+                .into())
+            }
+        }
+    };
+}
+
 fn generate_proof(private_key: &[u8; 32]) -> Result<Receipt, Box<dyn std::error::Error>> {
     println!("Generating ZK proof... (this may take a while)");
 
@@ -123,54 +473,56 @@ fn verify_proof(proof_path: &PathBuf) -> Result<(), Box<dyn std::error::Error>>
     }
     let binary_image = BinaryImage32x64::from_bytes(&binary_data);
 
+    // The guest commits this straight from the in-circuit image, so it's
+    // trusted the moment the proof verifies - no need to recompute it.
+    let ink_density: u32 = receipt.journal.decode()?;
+
     println!("✓ Proof verified successfully!");
     println!("  Address: 0x{}", hex::encode(address));
     println!("  Parameters: walks={}, steps={}", walks, steps);
     println!("  Binary image size: {} bytes (24x smaller than RGB!)", binary_image.data.len());
+    println!("  Ink density: {} pixels", ink_density);
     println!("  (Colors can be applied freely after verification)");
 
     Ok(())
 }
 
-fn mirror_half_to_full(half: &Image32x64, background: Pixel) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
-    let mut full_image = vec![background; 64 * 64];
+/// Mirror a `W`x`H` half-canvas into a full `(2*W)`x`H` image.
+fn mirror_half_to_full<const W: usize, const H: usize>(half: &Image<W, H>, background: Pixel) -> Vec<Pixel> {
+    let full_width = (W * 2) as u64;
+    let height = H as u64;
+    let mut full_image = vec![background; (full_width * height) as usize];
 
     // Copy left half and mirror to right half
-    for y in 0..64 {
-        for x in 0..32 {
+    for y in 0..height {
+        for x in 0..W as u64 {
             let pixel = half.get_pixel(x, y).unwrap_or(background);
-            full_image[(y * 64 + x) as usize] = pixel;
+            full_image[(y * full_width + x) as usize] = pixel;
 
-            let mirrored_x = 64 - x - 1;
-            full_image[(y * 64 + mirrored_x) as usize] = pixel;
+            let mirrored_x = full_width - x - 1;
+            full_image[(y * full_width + mirrored_x) as usize] = pixel;
         }
     }
 
-    // Convert to ImageBuffer
-    let pixel_data: Vec<u8> = full_image
-        .iter()
-        .flat_map(|p| vec![p.r, p.g, p.b])
-        .collect();
-
-    ImageBuffer::from_raw(64, 64, pixel_data).unwrap()
+    full_image
 }
 
 fn add_corner_stamps(
-    image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>,
+    image: &mut [Pixel],
+    width: u64,
+    height: u64,
     private_key: &[u8; 32],
-    foreground: Rgb<u8>,
-    background: Rgb<u8>,
+    foreground: Pixel,
+    background: Pixel,
     offset: u64,
 ) {
-    let width = 64u64;
-    let height = 64u64;
-
     // Top-left: bytes 0-7
-    stamp_corner(image, &private_key[0..8], offset, offset, foreground, background);
+    stamp_corner(image, width, &private_key[0..8], offset, offset, foreground, background);
 
     // Top-right: bytes 8-15
     stamp_corner(
         image,
+        width,
         &private_key[8..16],
         width - 8 - offset,
         offset,
@@ -181,6 +533,7 @@ fn add_corner_stamps(
     // Bottom-left: bytes 16-23
     stamp_corner(
         image,
+        width,
         &private_key[16..24],
         offset,
         height - 8 - offset,
@@ -191,6 +544,7 @@ fn add_corner_stamps(
     // Bottom-right: bytes 24-31
     stamp_corner(
         image,
+        width,
         &private_key[24..32],
         width - 8 - offset,
         height - 8 - offset,
@@ -200,50 +554,180 @@ fn add_corner_stamps(
 }
 
 fn stamp_corner(
-    image: &mut ImageBuffer<Rgb<u8>, Vec<u8>>,
+    image: &mut [Pixel],
+    width: u64,
     bytes: &[u8],
     start_x: u64,
     start_y: u64,
-    foreground: Rgb<u8>,
-    background: Rgb<u8>,
+    foreground: Pixel,
+    background: Pixel,
 ) {
     for (row, &byte) in bytes.iter().enumerate() {
         for col in 0..8 {
             let bit = (byte >> (7 - col)) & 1;
             let pixel = if bit == 1 { foreground } else { background };
-            image.put_pixel(
-                (start_x + col) as u32,
-                (start_y + row as u64) as u32,
-                pixel,
-            );
+            image[((start_y + row as u64) * width + start_x + col) as usize] = pixel;
         }
     }
 }
 
-fn upscale(image: &ImageBuffer<Rgb<u8>, Vec<u8>>, factor: u32) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
-    let (width, height) = image.dimensions();
-    let new_width = width * factor;
-    let new_height = height * factor;
+/// Private, ancillary, safe-to-copy PNG chunk type carrying the embedded
+/// commitment (address + walks + steps + binary image). Third byte is
+/// uppercase to keep the reserved bit conforming.
+const COMMITMENT_CHUNK_TYPE: [u8; 4] = *b"roRk";
+
+/// CRC-32 with the standard PNG polynomial, computed table-driven per the
+/// PNG spec (Annex D) so `embed_commitment`/`read_commitment` agree with
+/// any other tool that reads these chunks.
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut table = [0u32; 256];
+    for (n, entry) in table.iter_mut().enumerate() {
+        *entry = (0..8).fold(n as u32, |a, _| if a & 1 == 1 { 0xEDB88320 ^ (a >> 1) } else { a >> 1 });
+    }
 
-    let mut new_image = ImageBuffer::new(new_width, new_height);
+    !bytes.iter().fold(0xFFFF_FFFFu32, |a, &b| (a >> 8) ^ table[((a ^ b as u32) & 0xFF) as usize])
+}
 
-    for y in 0..height {
-        for x in 0..width {
-            let pixel = image.get_pixel(x, y);
-            for dy in 0..factor {
-                for dx in 0..factor {
-                    new_image.put_pixel(x * factor + dx, y * factor + dy, *pixel);
-                }
+/// Encode a `FillMode` as a single byte for the commitment payload. Keep in
+/// sync with `fill_mode_from_byte` below.
+fn fill_mode_to_byte(fill_mode: FillMode) -> u8 {
+    match fill_mode {
+        FillMode::Walk => 0,
+        FillMode::Turbulence => 1,
+        FillMode::Hilbert => 2,
+    }
+}
+
+/// Inverse of `fill_mode_to_byte`.
+fn fill_mode_from_byte(byte: u8) -> Result<FillMode, Box<dyn std::error::Error>> {
+    match byte {
+        0 => Ok(FillMode::Walk),
+        1 => Ok(FillMode::Turbulence),
+        2 => Ok(FillMode::Hilbert),
+        other => Err(format!("unknown fill mode byte in commitment: {other}").into()),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn build_commitment_payload(
+    address: &[u8; 20],
+    walks: u64,
+    steps: u64,
+    horizontal_bias: Fx32,
+    vertical_bias: Fx32,
+    fill_mode: FillMode,
+    turbulence_octaves: u32,
+    turbulence_threshold: f32,
+    binary_image: &BinaryImage32x64,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(20 + 8 + 8 + 4 + 4 + 1 + 4 + 4 + 256);
+    payload.extend_from_slice(address);
+    payload.extend_from_slice(&walks.to_le_bytes());
+    payload.extend_from_slice(&steps.to_le_bytes());
+    payload.extend_from_slice(&horizontal_bias.0.to_le_bytes());
+    payload.extend_from_slice(&vertical_bias.0.to_le_bytes());
+    payload.push(fill_mode_to_byte(fill_mode));
+    payload.extend_from_slice(&turbulence_octaves.to_le_bytes());
+    payload.extend_from_slice(&turbulence_threshold.to_le_bytes());
+    payload.extend_from_slice(&binary_image.data);
+    payload
+}
+
+/// Splice a commitment chunk into a PNG written by `PngBackend`, right
+/// before `IEND` (the last 12 bytes: 4-byte length, 4-byte type, 4-byte
+/// CRC, zero-length data).
+fn embed_commitment(path: &PathBuf, payload: &[u8]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut bytes = fs::read(path)?;
+    let iend_pos = bytes.len().checked_sub(12).ok_or("file too short to be a PNG")?;
+
+    let mut chunk = Vec::with_capacity(8 + payload.len() + 4);
+    chunk.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    chunk.extend_from_slice(&COMMITMENT_CHUNK_TYPE);
+    chunk.extend_from_slice(payload);
+    let crc_span = &chunk[4..];
+    chunk.extend_from_slice(&crc32(crc_span).to_be_bytes());
+
+    bytes.splice(iend_pos..iend_pos, chunk);
+    fs::write(path, bytes)?;
+    Ok(())
+}
+
+/// Read the commitment chunk back out of a PNG, verifying its CRC along
+/// the way, and decode it into `(address, walks, steps, horizontal_bias, vertical_bias, fill_mode, turbulence_octaves, turbulence_threshold, binary_image)`.
+#[allow(clippy::type_complexity)]
+fn read_commitment(path: &PathBuf) -> Result<([u8; 20], u64, u64, Fx32, Fx32, FillMode, u32, f32, BinaryImage32x64), Box<dyn std::error::Error>> {
+    let bytes = fs::read(path)?;
+    let mut pos = 8; // past the 8-byte PNG signature
+
+    while pos + 8 <= bytes.len() {
+        let length = u32::from_be_bytes(bytes[pos..pos + 4].try_into()?) as usize;
+        let chunk_type: [u8; 4] = bytes[pos + 4..pos + 8].try_into()?;
+        let data_start = pos + 8;
+        let data_end = data_start + length;
+        let crc_end = data_end + 4;
+
+        if chunk_type == COMMITMENT_CHUNK_TYPE {
+            let data = &bytes[data_start..data_end];
+            let stored_crc = u32::from_be_bytes(bytes[data_end..crc_end].try_into()?);
+            if crc32(&bytes[pos + 4..data_end]) != stored_crc {
+                return Err("commitment chunk failed CRC check".into());
             }
+
+            let address: [u8; 20] = data[0..20].try_into()?;
+            let walks = u64::from_le_bytes(data[20..28].try_into()?);
+            let steps = u64::from_le_bytes(data[28..36].try_into()?);
+            let horizontal_bias = Fx32(i32::from_le_bytes(data[36..40].try_into()?));
+            let vertical_bias = Fx32(i32::from_le_bytes(data[40..44].try_into()?));
+            let fill_mode = fill_mode_from_byte(data[44])?;
+            let turbulence_octaves = u32::from_le_bytes(data[45..49].try_into()?);
+            let turbulence_threshold = f32::from_le_bytes(data[49..53].try_into()?);
+            let binary_image = BinaryImage32x64::from_bytes(&data[53..309]);
+            return Ok((address, walks, steps, horizontal_bias, vertical_bias, fill_mode, turbulence_octaves, turbulence_threshold, binary_image));
         }
+
+        pos = crc_end;
+    }
+
+    Err("no commitment chunk found in PNG".into())
+}
+
+fn check_png(path: &PathBuf, private_key: &[u8; 32]) -> Result<(), Box<dyn std::error::Error>> {
+    println!("Checking embedded commitment...");
+
+    let (address, walks, steps, horizontal_bias, vertical_bias, fill_mode, turbulence_octaves, turbulence_threshold, binary_image) = read_commitment(path)?;
+
+    if derive_address(private_key)? != address {
+        return Err("embedded address does not match private key".into());
+    }
+
+    let recomputed = generate_rorschach_binary::<32, 64>(private_key, walks, steps, horizontal_bias, vertical_bias, fill_mode, turbulence_octaves, turbulence_threshold);
+    if recomputed.data != binary_image.data {
+        return Err("recomputed pattern does not match embedded commitment".into());
     }
 
-    new_image
+    println!("✓ Commitment verified - pixels match the private key!");
+    println!("  Address: 0x{}", hex::encode(address));
+    println!("  Parameters: walks={}, steps={}", walks, steps);
+
+    Ok(())
 }
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cli = Cli::parse();
 
+    // --size only applies to the unproved generation path - the zkVM guest's
+    // canvas is a fixed compile-time size, so a proved/checked image is
+    // always the default 32x64.
+    if (cli.prove || cli.check.is_some()) && cli.size != CanvasSize::DEFAULT {
+        return Err("--size is only supported without --prove/--check - the zkVM guest's canvas is a fixed 32x64".into());
+    }
+    if (cli.prove || cli.check.is_some()) && cli.fill_mode != FillModeArg::Walk {
+        return Err("--fill-mode is only supported without --prove/--check - the zkVM guest's fill mode is fixed at walk".into());
+    }
+    if (cli.prove || cli.check.is_some()) && cli.diff_key.is_some() {
+        return Err("--diff-key is only supported without --prove/--check".into());
+    }
+
     // Verify mode
     if let Some(proof_path) = cli.verify {
         return verify_proof(&proof_path);
@@ -251,10 +735,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Generate or parse private key
     let private_key: [u8; 32] = if let Some(pk_hex) = cli.private_key {
-        let pk_hex = pk_hex.trim_start_matches("0x");
-        hex::decode(pk_hex)?
-            .try_into()
-            .map_err(|_| "Private key must be exactly 32 bytes")?
+        parse_private_key(&pk_hex)?
     } else if cli.generate_key {
         let signer = PrivateKeySigner::random();
         let pk_bytes = signer.credential().to_bytes();
@@ -265,8 +746,18 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         return Err("Must provide --private-key or --generate-key".into());
     };
 
+    // Offline commitment check mode
+    if let Some(check_path) = cli.check {
+        return check_png(&check_path, &private_key);
+    }
+
     // Proof generation mode
     if cli.prove {
+        // Fail fast on an invalid key before paying for proof generation -
+        // the guest can't gracefully reject it, and cross-checking only
+        // after the prover runs would waste that work on a doomed key.
+        derive_address(&private_key)?;
+
         let receipt = generate_proof(&private_key)?;
 
         // Extract public outputs (binary image, not RGB!)
@@ -282,10 +773,22 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         }
         let binary_image = BinaryImage32x64::from_bytes(&binary_data);
 
+        // The guest commits this straight from the in-circuit image, so it's
+        // trusted the moment the proof verifies - no need to recompute it.
+        let ink_density: u32 = receipt.journal.decode()?;
+
+        // Cross-check: the committed address must match the one derived from
+        // the private key we just handed the guest, or the journal isn't
+        // actually bound to this key.
+        if derive_address(&private_key)? != address {
+            return Err("committed address does not match private key".into());
+        }
+
         println!("✓ Proof generated successfully!");
         println!("  Address: 0x{}", hex::encode(address));
         println!("  Parameters: walks={}, steps={}", walks, steps);
         println!("  Binary image size: {} bytes (24x smaller than RGB!)", binary_image.data.len());
+        println!("  Ink density: {} pixels", ink_density);
 
         // Save proof
         let proof_path = cli.output.with_extension("proof");
@@ -303,20 +806,25 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
         // Add stamps if requested
         if !cli.no_stamp {
-            add_corner_stamps(
-                &mut full_image,
-                &private_key,
-                Rgb(foreground.to_rgb_array()),
-                Rgb(background.to_rgb_array()),
-                cli.stamp_offset
-            );
+            add_corner_stamps(&mut full_image, 64, 64, &private_key, foreground, background, cli.stamp_offset);
         }
 
-        // Upscale to 512×512
-        let final_image = upscale(&full_image, 8);
+        // Render through whichever backend was selected and save
+        match cli.format {
+            OutputFormat::Png => render(&mut PngBackend::new(cli.scale), &full_image, 64, 64, &cli.output)?,
+            OutputFormat::Svg => render(&mut SvgBackend::new(cli.scale), &full_image, 64, 64, &cli.output)?,
+            OutputFormat::Ascii => render(&mut AsciiBackend::new(background), &full_image, 64, 64, &cli.output)?,
+        }
+
+        if cli.format == OutputFormat::Png {
+            // The guest always generates with neutral bias and FillMode::Walk
+            // (see methods/guest), so that's what a later --check needs to
+            // recompute against.
+            let payload = build_commitment_payload(&address, walks, steps, Fx32::ONE, Fx32::ONE, FillMode::Walk, cli.octaves, cli.turbulence_threshold, &binary_image);
+            embed_commitment(&cli.output, &payload)?;
+            println!("  Commitment embedded (check offline with --check)");
+        }
 
-        // Save final image
-        final_image.save(&cli.output)?;
         println!("  Image saved to: {}", cli.output.display());
         println!("  (Colors applied after verification - can be changed freely!)");
 
@@ -337,33 +845,90 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let foreground = cli.color.to_pixel();
     let background = cli.background.to_pixel();
+    let horizontal_bias = bias_from_f64(cli.bias_x);
+    let vertical_bias = bias_from_f64(cli.bias_y);
+    let fill_mode: FillMode = cli.fill_mode.into();
+    let diff_key: Option<[u8; 32]> = cli.diff_key.as_deref().map(parse_private_key).transpose()?;
+
+    with_canvas_size!(cli.size, |W, H| {
+        // Generate half-canvas - either the normal pattern, or (with
+        // --diff-key) the XOR of two keys' patterns for visual diffing.
+        let half_image = if let Some(diff_key) = diff_key {
+            let primary = generate_rorschach_binary::<W, H>(&private_key, walks, steps, horizontal_bias, vertical_bias, fill_mode, cli.octaves, cli.turbulence_threshold);
+            let other = generate_rorschach_binary::<W, H>(&diff_key, walks, steps, horizontal_bias, vertical_bias, fill_mode, cli.octaves, cli.turbulence_threshold);
+            binary_to_rgb(&primary.xor(&other), foreground, background)
+        } else {
+            generate_rorschach_half::<W, H>(
+                &private_key,
+                walks,
+                steps,
+                foreground,
+                background,
+                horizontal_bias,
+                vertical_bias,
+                fill_mode,
+                cli.octaves,
+                cli.turbulence_threshold,
+            )
+        };
+
+        // Per-walk difference images: each walk's incremental contribution
+        // is the symmetric difference (XOR) between the union before and
+        // after adding it, since the union only ever grows.
+        if cli.debug > 0 && fill_mode == FillMode::Walk {
+            let layers = generate_rorschach_binary_layers::<W, H>(&private_key, walks, steps, horizontal_bias, vertical_bias, fill_mode, cli.octaves, cli.turbulence_threshold);
+            let mut cumulative = BinaryImage::<W, H>::new();
+            for (i, layer) in layers.iter().enumerate() {
+                let mut next = cumulative.clone();
+                next.union_in_place(layer);
+                println!("  Walk {}: +{} new pixels", i + 1, next.xor(&cumulative).popcount());
+                cumulative = next;
+            }
+        }
 
-    // Generate half-canvas
-    let half_image = generate_rorschach_half(&private_key, walks, steps, foreground, background);
-
-    // Mirror to full 64×64
-    let mut full_image = mirror_half_to_full(&half_image, background);
+        // Mirror to full (2*W)xH
+        let mut full_image = mirror_half_to_full(&half_image, background);
+        let full_width = (W * 2) as u32;
+        let full_height = H as u32;
 
-    // Add stamps if requested
-    if !cli.no_stamp {
-        add_corner_stamps(
-            &mut full_image,
-            &private_key,
-            cli.color.to_rgb(),
-            cli.background.to_rgb(),
-            cli.stamp_offset,
-        );
-    }
+        // Add stamps if requested
+        if !cli.no_stamp {
+            add_corner_stamps(
+                &mut full_image,
+                full_width as u64,
+                full_height as u64,
+                &private_key,
+                foreground,
+                background,
+                cli.stamp_offset,
+            );
+        }
 
-    // Upscale to 512×512
-    let final_image = upscale(&full_image, 8);
+        // Render through whichever backend was selected and save
+        match cli.format {
+            OutputFormat::Png => render(&mut PngBackend::new(cli.scale), &full_image, full_width, full_height, &cli.output)?,
+            OutputFormat::Svg => render(&mut SvgBackend::new(cli.scale), &full_image, full_width, full_height, &cli.output)?,
+            OutputFormat::Ascii => render(&mut AsciiBackend::new(background), &full_image, full_width, full_height, &cli.output)?,
+        }
 
-    // Save
-    final_image.save(&cli.output)?;
+        if cli.format == OutputFormat::Png {
+            if cli.size == CanvasSize::DEFAULT {
+                let address = derive_address(&private_key)?;
+                let binary_image = generate_rorschach_binary::<32, 64>(&private_key, walks, steps, horizontal_bias, vertical_bias, fill_mode, cli.octaves, cli.turbulence_threshold);
+                let payload = build_commitment_payload(&address, walks, steps, horizontal_bias, vertical_bias, fill_mode, cli.octaves, cli.turbulence_threshold, &binary_image);
+                embed_commitment(&cli.output, &payload)?;
+                if cli.debug > 0 {
+                    println!("Commitment embedded (check offline with --check)");
+                }
+            } else if cli.debug > 0 {
+                println!("Skipping commitment embed: --size doesn't match the zkVM guest's fixed 32x64 canvas");
+            }
+        }
 
-    if cli.debug > 0 {
-        println!("Image saved to: {}", cli.output.display());
-    }
+        if cli.debug > 0 {
+            println!("Image saved to: {}", cli.output.display());
+        }
 
-    Ok(())
+        Ok(())
+    })
 }
@@ -4,9 +4,7 @@
 extern crate alloc;
 
 use risc0_zkvm::guest::env;
-use ror_core::{derive_parameters, generate_rorschach_half, Pixel};
-use k256::ecdsa::{SigningKey, VerifyingKey};
-use sha3::{Digest, Keccak256};
+use ror_core::{derive_address, derive_parameters, generate_rorschach_binary, FillMode, Fx32, DEFAULT_TURBULENCE_OCTAVES, DEFAULT_TURBULENCE_THRESHOLD};
 
 risc0_zkvm::guest::entry!(main);
 
@@ -14,32 +12,47 @@ fn main() {
     // Read private key from host (kept private)
     let private_key: [u8; 32] = env::read();
 
-    // Derive public key using secp256k1
-    let signing_key = SigningKey::from_bytes(&private_key.into())
-        .expect("Invalid private key");
-    let verifying_key = VerifyingKey::from(&signing_key);
-    let public_key_bytes = verifying_key.to_encoded_point(false);
-    let public_key_uncompressed = public_key_bytes.as_bytes();
-
-    // Derive Ethereum address (last 20 bytes of keccak256(public_key))
-    let mut hasher = Keccak256::new();
-    hasher.update(&public_key_uncompressed[1..]); // Skip the 0x04 prefix
-    let hash = hasher.finalize();
-    let address: [u8; 20] = hash[12..32].try_into().unwrap();
+    // Derive the Ethereum address in-circuit, so the committed journal binds
+    // the address to the same secret that produced the pattern.
+    let address = derive_address(&private_key).expect("private key must be a valid secp256k1 scalar");
 
     // Derive generation parameters from private key
     let (walks, steps) = derive_parameters(&private_key);
 
-    // Define colors (hardcoded for now, could be public inputs)
-    let foreground = Pixel::new(255, 217, 102);
-    let background = Pixel::new(255, 0, 129);
-
-    // Generate the Rorschach half-canvas deterministically
-    let image = generate_rorschach_half(&private_key, walks, steps, foreground, background);
+    // Generate the packed binary pattern deterministically. Bias is left
+    // neutral here: it's a host-side display tuning knob, not something the
+    // committed journal needs to bind to. The guest's canvas size - and fill
+    // mode - are fixed at compile time: it's a circuit, not something a CLI
+    // flag can resize or restyle. Binary (not RGB) keeps the journal small
+    // and color-agnostic - the host applies colors after verification via
+    // `binary_to_rgb`.
+    let binary_image = generate_rorschach_binary::<32, 64>(
+        &private_key,
+        walks,
+        steps,
+        Fx32::ONE,
+        Fx32::ONE,
+        FillMode::Walk,
+        DEFAULT_TURBULENCE_OCTAVES,
+        DEFAULT_TURBULENCE_THRESHOLD,
+    );
 
     // Commit public outputs to the journal
     env::commit(&address);
     env::commit(&walks);
     env::commit(&steps);
-    env::commit(&image.to_bytes());
+
+    // Commit the packed image as 8 32-byte chunks - the host's journal
+    // decode (see the read loops in `verify_proof` and the `--prove` path)
+    // expects exactly this shape.
+    let bytes = binary_image.to_bytes();
+    for chunk in bytes.chunks(32) {
+        let array: [u8; 32] = chunk.try_into().expect("binary image is a whole number of 32-byte chunks");
+        env::commit(&array);
+    }
+
+    // Commit the ink density too - it's a single popcount over bits already
+    // in the circuit, so committing it costs far less than having the host
+    // recompute it from the full image after verification.
+    env::commit(&binary_image.popcount());
 }
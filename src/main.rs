@@ -2,32 +2,41 @@ use std::{path::PathBuf, str::FromStr};
 
 use alloy::signers::local::PrivateKeySigner;
 use clap::Parser;
-use image::{ImageBuffer, Pixel, Rgb};
+use image::{ImageBuffer, Rgb, Rgba};
 use rand::prelude::*;
 use rand::SeedableRng;
+// ChaCha20Rng rather than StdRng: StdRng's algorithm is explicitly not
+// stable across `rand` releases, so a future upgrade could silently change
+// every generated pattern for the same private key.
+use rand_chacha::ChaCha20Rng;
 
 struct PixelImage<T> {
     width: u64,
     height: u64,
-    // image::Rgb<u8>
+    // image::Rgba<u8>
     pixels: Vec<T>,
+    // Number of times each pixel has been inked, used to key the visit-count
+    // color gradient; unrelated to alpha. Mirroring and upscaling build a fresh
+    // image, so the counts start over at zero there.
+    visits: Vec<u16>,
 }
 
-impl PixelImage<Rgb<u8>> {
-    fn new(width: u64, height: u64, rgb: Option<Rgb<u8>>) -> Self {
-        let pixel = rgb.unwrap_or(Rgb([255, 255, 255]));
-        let mut pixels: Vec<Rgb<u8>> = Vec::with_capacity((width * height) as usize);
+impl PixelImage<Rgba<u8>> {
+    fn new(width: u64, height: u64, rgba: Option<Rgba<u8>>) -> Self {
+        let pixel = rgba.unwrap_or(Rgba([255, 255, 255, 255]));
+        let mut pixels: Vec<Rgba<u8>> = Vec::with_capacity((width * height) as usize);
         for _ in 0..width * height {
-            pixels.push(rgb.unwrap_or(pixel));
+            pixels.push(pixel);
         }
         Self {
             width,
             height,
+            visits: vec![0u16; (width * height) as usize],
             pixels,
         }
     }
 
-    fn get_pixel(&self, x: u64, y: u64) -> Option<&Rgb<u8>> {
+    fn get_pixel(&self, x: u64, y: u64) -> Option<&Rgba<u8>> {
         if x < self.width && y < self.height {
             Some(&self.pixels[(y * self.width + x) as usize])
         } else {
@@ -35,17 +44,54 @@ impl PixelImage<Rgb<u8>> {
         }
     }
 
-    fn set_pixel(&mut self, x: u64, y: u64, pixel: Rgb<u8>) {
+    fn set_pixel(&mut self, x: u64, y: u64, pixel: Rgba<u8>) {
         if x < self.width && y < self.height {
-            self.pixels[(y * self.width + x) as usize] = pixel.into();
+            self.pixels[(y * self.width + x) as usize] = pixel;
         }
     }
 
+    /// Composite `pixel` over whatever is already at `(x, y)` using Porter-Duff
+    /// source-over, so repeatedly-inked pixels accumulate density instead of
+    /// being clobbered, and bump that pixel's visit counter.
+    fn composite_pixel(&mut self, x: u64, y: u64, pixel: Rgba<u8>) {
+        if let Some(&dst) = self.get_pixel(x, y) {
+            self.set_pixel(x, y, source_over(dst, pixel));
+            let idx = (y * self.width + x) as usize;
+            self.visits[idx] = self.visits[idx].saturating_add(1);
+        }
+    }
+
+    /// Recolor every visited pixel along a CIE L*a*b* gradient keyed on how
+    /// many times it was visited, so density maps to a perceptually-smooth
+    /// hue shift instead of a flat ink color. Preserves each pixel's alpha.
+    fn apply_visit_gradient(&mut self, start: Rgba<u8>, end: Rgba<u8>) {
+        let max_visits = *self.visits.iter().max().unwrap_or(&0);
+        if max_visits == 0 {
+            return;
+        }
+
+        let lab_start = rgb_to_lab([start.0[0], start.0[1], start.0[2]]);
+        let lab_end = rgb_to_lab([end.0[0], end.0[1], end.0[2]]);
+
+        for idx in 0..self.pixels.len() {
+            let visits = self.visits[idx];
+            if visits == 0 {
+                continue;
+            }
+            let t = visits as f32 / max_visits as f32;
+            let [r, g, b] = lab_to_rgb(lerp_lab(&lab_start, &lab_end, t));
+            let alpha = self.pixels[idx].0[3];
+            self.pixels[idx] = Rgba([r, g, b, alpha]);
+        }
+    }
+
+    /// Flatten onto the (already-opaque) background by dropping the alpha
+    /// channel, ready for a format that has no notion of transparency.
     fn export_image(&self) -> ImageBuffer<Rgb<u8>, Vec<u8>> {
         let pixel_data = self
             .pixels
             .iter()
-            .flat_map(|p| p.channels().to_vec())
+            .flat_map(|p| p.0[..3].to_vec())
             .collect::<Vec<u8>>();
         ImageBuffer::from_raw(self.width as u32, self.height as u32, pixel_data).unwrap()
     }
@@ -68,16 +114,131 @@ impl PixelImage<Rgb<u8>> {
     }
 }
 
+/// Standard non-premultiplied Porter-Duff "source-over" compositing, done in
+/// integer math so host and ZK guest stay bit-identical:
+/// `out_c = src_c*sa + dst_c*(1-sa)`, `out_a = src_a + dst_a*(1-sa)`, with
+/// `sa = src_a/255` folded into the `/255` divisions below.
+fn source_over(dst: Rgba<u8>, src: Rgba<u8>) -> Rgba<u8> {
+    let src_a = src.0[3] as u32;
+    let dst_a = dst.0[3] as u32;
+    let inv_a = 255 - src_a;
+
+    let blend_channel = |s: u8, d: u8| -> u8 { ((s as u32 * src_a + d as u32 * inv_a) / 255) as u8 };
+
+    let out_r = blend_channel(src.0[0], dst.0[0]);
+    let out_g = blend_channel(src.0[1], dst.0[1]);
+    let out_b = blend_channel(src.0[2], dst.0[2]);
+    let out_a = (src_a + dst_a * inv_a / 255).min(255) as u8;
+
+    Rgba([out_r, out_g, out_b, out_a])
+}
+
+/// A color in CIE L*a*b*, used as the perceptually-uniform interpolation
+/// space for the visit-count gradient.
+#[derive(Clone, Copy)]
+struct Lab {
+    l: f32,
+    a: f32,
+    b: f32,
+}
+
+const D65_WHITE: (f32, f32, f32) = (0.95047, 1.0, 1.08883);
+
+fn srgb_to_linear(c: u8) -> f32 {
+    let c = c as f32 / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> u8 {
+    let c = c.clamp(0.0, 1.0);
+    let s = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    (s.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn rgb_to_lab(rgb: [u8; 3]) -> Lab {
+    let r = srgb_to_linear(rgb[0]);
+    let g = srgb_to_linear(rgb[1]);
+    let b = srgb_to_linear(rgb[2]);
+
+    // sRGB -> XYZ, D65 matrix
+    let x = 0.4124564 * r + 0.3575761 * g + 0.1804375 * b;
+    let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+    let z = 0.0193339 * r + 0.1191920 * g + 0.9503041 * b;
+
+    fn f(t: f32) -> f32 {
+        if t > 0.008856 {
+            t.powf(1.0 / 3.0)
+        } else {
+            7.787 * t + 16.0 / 116.0
+        }
+    }
+
+    let (xn, yn, zn) = D65_WHITE;
+    let fx = f(x / xn);
+    let fy = f(y / yn);
+    let fz = f(z / zn);
+
+    Lab {
+        l: 116.0 * fy - 16.0,
+        a: 500.0 * (fx - fy),
+        b: 200.0 * (fy - fz),
+    }
+}
+
+fn lab_to_rgb(lab: Lab) -> [u8; 3] {
+    let (xn, yn, zn) = D65_WHITE;
+
+    let fy = (lab.l + 16.0) / 116.0;
+    let fx = fy + lab.a / 500.0;
+    let fz = fy - lab.b / 200.0;
+
+    fn finv(t: f32) -> f32 {
+        let cubed = t * t * t;
+        if cubed > 0.008856 {
+            cubed
+        } else {
+            (t - 16.0 / 116.0) / 7.787
+        }
+    }
+
+    let x = xn * finv(fx);
+    let y = yn * finv(fy);
+    let z = zn * finv(fz);
+
+    // XYZ -> sRGB, D65 matrix
+    let r = 3.2404542 * x - 1.5371385 * y - 0.4985314 * z;
+    let g = -0.9692660 * x + 1.8760108 * y + 0.0415560 * z;
+    let b = 0.0556434 * x - 0.2040259 * y + 1.0572252 * z;
+
+    [linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b)]
+}
+
+fn lerp_lab(a: &Lab, b: &Lab, t: f32) -> Lab {
+    Lab {
+        l: a.l + (b.l - a.l) * t,
+        a: a.a + (b.a - a.a) * t,
+        b: a.b + (b.b - a.b) * t,
+    }
+}
+
 struct Drawyer<T> {
     cursor_x: u64,
     cursor_y: u64,
     image: PixelImage<T>,
 
-    rng: StdRng,
+    rng: ChaCha20Rng,
 }
 
-impl Drawyer<Rgb<u8>> {
-    fn new(width: u64, height: u64, rng: StdRng) -> Self {
+impl Drawyer<Rgba<u8>> {
+    fn new(width: u64, height: u64, rng: ChaCha20Rng) -> Self {
         Self {
             cursor_x: 0,
             cursor_y: 0,
@@ -87,15 +248,15 @@ impl Drawyer<Rgb<u8>> {
     }
 
     fn with_seed(width: u64, height: u64, seed: u64) -> Self {
-        let rng = StdRng::seed_from_u64(seed);
+        let rng = ChaCha20Rng::seed_from_u64(seed);
         Self::new(width, height, rng)
     }
 
-    fn rng(&mut self) -> &mut StdRng {
+    fn rng(&mut self) -> &mut ChaCha20Rng {
         &mut self.rng
     }
 
-    fn with_image(rng: StdRng, image: PixelImage<Rgb<u8>>) -> Self {
+    fn with_image(rng: ChaCha20Rng, image: PixelImage<Rgba<u8>>) -> Self {
         Self {
             cursor_x: 0,
             cursor_y: 0,
@@ -119,8 +280,10 @@ impl Drawyer<Rgb<u8>> {
         self.cursor_y = rng.random_range(top_margin..bottom_margin);
     }
 
-    fn draw(&mut self, pixel: Rgb<u8>) {
-        self.image.set_pixel(self.cursor_x, self.cursor_y, pixel);
+    /// Deposit ink at the cursor, composited over whatever's already there so
+    /// overlapping strokes accumulate toward full saturation.
+    fn draw(&mut self, pixel: Rgba<u8>) {
+        self.image.composite_pixel(self.cursor_x, self.cursor_y, pixel);
     }
 
     fn move_cursor(&mut self, x: u64, y: u64) {
@@ -171,7 +334,8 @@ impl Drawyer<Rgb<u8>> {
 
 struct Artist<T> {
     drawyer: Drawyer<T>,
-    pixel: Rgb<u8>,
+    pixel: Rgba<u8>,
+    ink_alpha: u8,
 }
 
 enum Decision {
@@ -181,7 +345,102 @@ enum Decision {
     Down,
 }
 
-impl Artist<Rgb<u8>> {
+/// Which generator fills the left half of the canvas before mirroring
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum FillMode {
+    /// Memoryless random walk (original behaviour)
+    Walk,
+    /// Gradient-noise fractal turbulence ink-blot
+    Turbulence,
+    /// Hilbert space-filling-curve coverage
+    Hilbert,
+}
+
+/// Smallest power of two `>= x`
+fn next_pow2(x: u64) -> u64 {
+    let mut n = 1;
+    while n < x {
+        n *= 2;
+    }
+    n
+}
+
+/// Decode Hilbert-curve distance `d` into `(x, y)` for a square of side `n = 2^k`
+fn hilbert_d2xy(n: u64, d: u64) -> (u64, u64) {
+    let mut x = 0u64;
+    let mut y = 0u64;
+    let mut t = d;
+
+    let mut s = 1;
+    while s < n {
+        let rx = 1 & (t / 2);
+        let ry = 1 & (t ^ rx);
+
+        if ry == 0 {
+            if rx == 1 {
+                x = s - 1 - x;
+                y = s - 1 - y;
+            }
+            core::mem::swap(&mut x, &mut y);
+        }
+
+        x += s * rx;
+        y += s * ry;
+        t /= 4;
+        s *= 2;
+    }
+
+    (x, y)
+}
+
+/// Fade curve `6t^5 - 15t^4 + 10t^3` used to smooth Perlin cell interpolation
+fn perlin_fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn perlin_lerp(t: f32, a: f32, b: f32) -> f32 {
+    a + t * (b - a)
+}
+
+/// Gradient dot-product for one of the 4 standard 2D gradient directions,
+/// selected by the low 2 bits of the permutation-table entry
+fn perlin_grad(hash: u8, x: f32, y: f32) -> f32 {
+    match hash & 3 {
+        0 => x + y,
+        1 => -x + y,
+        2 => x - y,
+        _ => -x - y,
+    }
+}
+
+/// Classic Perlin gradient noise over a 256-entry permutation table
+fn perlin_noise(perm: &[u8; 256], x: f32, y: f32) -> f32 {
+    let xi = (x.floor() as i32 & 255) as usize;
+    let yi = (y.floor() as i32 & 255) as usize;
+    let xf = x - x.floor();
+    let yf = y - y.floor();
+
+    let u = perlin_fade(xf);
+    let v = perlin_fade(yf);
+
+    let perm_x = perm[xi] as usize;
+    let perm_x1 = perm[(xi + 1) & 255] as usize;
+
+    let aa = perm[(perm_x + yi) & 255];
+    let ab = perm[(perm_x + yi + 1) & 255];
+    let ba = perm[(perm_x1 + yi) & 255];
+    let bb = perm[(perm_x1 + yi + 1) & 255];
+
+    let x1 = perlin_lerp(u, perlin_grad(aa, xf, yf), perlin_grad(ba, xf - 1.0, yf));
+    let x2 = perlin_lerp(
+        u,
+        perlin_grad(ab, xf, yf - 1.0),
+        perlin_grad(bb, xf - 1.0, yf - 1.0),
+    );
+    perlin_lerp(v, x1, x2)
+}
+
+impl Artist<Rgba<u8>> {
     // fn new(width: u64, height: u64, seed: u64) -> Self {
 
     //     Self {
@@ -190,19 +449,27 @@ impl Artist<Rgb<u8>> {
     //     }
     // }
 
-    fn with_image(seed: [u8; 32], pixel: Rgb<u8>, image: PixelImage<Rgb<u8>>) -> Self {
-        let rng = StdRng::from_seed(seed);
+    fn with_image(seed: [u8; 32], pixel: Rgba<u8>, ink_alpha: u8, image: PixelImage<Rgba<u8>>) -> Self {
+        let rng = ChaCha20Rng::from_seed(seed);
         Self {
             drawyer: Drawyer::with_image(rng, image),
             pixel,
+            ink_alpha,
         }
     }
 
-    fn rng(&mut self) -> &mut StdRng {
+    fn rng(&mut self) -> &mut ChaCha20Rng {
         self.drawyer.rng()
     }
 
-    fn draw(&mut self, pixel: Rgb<u8>) {
+    /// The foreground color at the configured ink alpha, for use with
+    /// compositing draw calls (as opposed to `self.pixel`, which stays fully
+    /// opaque for stamps and thresholded fills).
+    fn ink_pixel(&self) -> Rgba<u8> {
+        Rgba([self.pixel.0[0], self.pixel.0[1], self.pixel.0[2], self.ink_alpha])
+    }
+
+    fn draw(&mut self, pixel: Rgba<u8>) {
         self.drawyer.draw(pixel);
     }
 
@@ -315,7 +582,7 @@ impl Artist<Rgb<u8>> {
     /// Encode private key as corner stamps (like playing cards)
     /// Each corner gets 8 bytes (64 bits) encoded as an 8×8 binary grid
     /// Uses the image's color palette (foreground pixel for 1, background for 0)
-    fn private_key_stamp(&mut self, pk: &[u8; 32], background: Rgb<u8>, offset: u64) {
+    fn private_key_stamp(&mut self, pk: &[u8; 32], background: Rgba<u8>, offset: u64) {
         // Split private key into 4 chunks of 8 bytes each
         // Top-left: bytes 0-7
         // Top-right: bytes 8-15
@@ -340,7 +607,7 @@ impl Artist<Rgb<u8>> {
 
     /// Encode 8 bytes as an 8×8 binary grid at given corner position
     /// Each byte becomes one row of 8 pixels
-    fn stamp_corner(&mut self, bytes: &[u8], start_x: u64, start_y: u64, background: Rgb<u8>) {
+    fn stamp_corner(&mut self, bytes: &[u8], start_x: u64, start_y: u64, background: Rgba<u8>) {
         for (row, &byte) in bytes.iter().enumerate() {
             for col in 0..8 {
                 let bit = (byte >> (7 - col)) & 1;
@@ -354,17 +621,100 @@ impl Artist<Rgb<u8>> {
         }
     }
 
-    fn draw_random(&mut self, steps: u64, walks: u64) -> () {
+    fn draw_random(&mut self, steps: u64, walks: u64, color_start: Rgba<u8>, color_end: Rgba<u8>) {
+        let ink = self.ink_pixel();
         for _ in 0..walks {
             self.drawyer.random_cursor();
-            self.drawyer.draw(self.pixel);
+            self.drawyer.draw(ink);
             for _ in 0..steps {
                 let direction = self.decide_direction();
                 self.move_cursor_by_decision(direction);
-                self.drawyer.draw(self.pixel);
+                self.drawyer.draw(ink);
             }
         }
 
+        self.drawyer.image.apply_visit_gradient(color_start, color_end);
+        self.mirror();
+    }
+
+    /// Build a 256-entry permutation table by Fisher-Yates shuffling `[0..256]`
+    /// using the artist's own RNG, so the noise field is seeded deterministically
+    /// from the private key.
+    fn build_permutation_table(&mut self) -> [u8; 256] {
+        let mut perm = [0u8; 256];
+        for (i, slot) in perm.iter_mut().enumerate() {
+            *slot = i as u8;
+        }
+        for i in (1..256).rev() {
+            let j = self.rng().random_range(0..=i);
+            perm.swap(i, j);
+        }
+        perm
+    }
+
+    /// Fill the left half with a fractal-turbulence ink blot instead of a random
+    /// walk: a fractal sum of `octaves` of gradient noise, `abs`-folded each
+    /// octave (the "turbulence" variant), thresholded per-pixel.
+    fn draw_turbulence(&mut self, octaves: u32, threshold: f32, background: Rgba<u8>) {
+        let perm = self.build_permutation_table();
+        let width = self.drawyer.image.width;
+        let height = self.drawyer.image.height;
+
+        for y in 0..height {
+            for x in 0..width / 2 {
+                let mut freq = 1.0 / 16.0;
+                let mut amp = 1.0;
+                let mut sum = 0.0;
+                let mut max_sum = 0.0;
+
+                for _ in 0..octaves {
+                    sum += perlin_noise(&perm, x as f32 * freq, y as f32 * freq).abs() * amp;
+                    max_sum += amp;
+                    freq *= 2.0;
+                    amp *= 0.5;
+                }
+
+                let normalized = if max_sum > 0.0 { sum / max_sum } else { 0.0 };
+                let pixel = if normalized > threshold {
+                    self.pixel
+                } else {
+                    background
+                };
+                self.drawyer.image.set_pixel(x, y, pixel);
+            }
+        }
+
+        self.mirror();
+    }
+
+    /// Fill the left-center region along a Hilbert space-filling curve instead
+    /// of a random walk, giving dense, evenly-connected coverage. `steps`
+    /// caps how many of the `n^2` curve points get inked; `walks` seeds a
+    /// random per-point skip so the fill still looks organic rather than a
+    /// perfectly solid block.
+    fn draw_hilbert(&mut self, steps: u64, walks: u64, background: Rgba<u8>) {
+        let left_margin = self.drawyer.image.width / 8;
+        let region_width = self.drawyer.image.width / 4;
+        let top_margin = self.drawyer.image.height / 4;
+        let region_height = self.drawyer.image.height / 2;
+
+        let n = next_pow2(region_width.max(region_height));
+        let point_count = (n * n).min(steps);
+        let skip_chance = walks;
+
+        for d in 0..point_count {
+            let (hx, hy) = hilbert_d2xy(n, d);
+            if hx >= region_width || hy >= region_height {
+                continue;
+            }
+
+            let skip = skip_chance > 0 && self.rng().random_range(0..skip_chance + 4) == 0;
+            let pixel = if skip { background } else { self.pixel };
+            self.drawyer
+                .image
+                .set_pixel(left_margin + hx, top_margin + hy, pixel);
+        }
+
         self.mirror();
     }
 }
@@ -373,6 +723,118 @@ fn _is_nth_bit_set(num: u64, n: u64) -> bool {
     (num & (1 << n)) != 0
 }
 
+/// Output format selected with `--format`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    /// Nearest-neighbor upscaled raster (the original behaviour)
+    Png,
+    /// Resolution-independent vector output
+    Svg,
+}
+
+/// A rasterizer that a `PixelImage` can be rendered through. Separates the
+/// generator (`Artist`/`Drawyer`) from how the result is written to disk, so
+/// adding another output format is a matter of one more impl.
+trait DrawingBackend {
+    fn draw_pixel(&mut self, x: u64, y: u64, color: Rgba<u8>);
+    fn present(&self, path: &PathBuf) -> Result<(), Box<dyn std::error::Error>>;
+}
+
+/// Nearest-neighbor upscale into a raster PNG, same as the original pipeline
+struct PngBackend {
+    image: PixelImage<Rgba<u8>>,
+    scale: u64,
+}
+
+impl PngBackend {
+    fn new(width: u64, height: u64, scale: u64) -> Self {
+        Self {
+            image: PixelImage::new(width, height, None),
+            scale,
+        }
+    }
+}
+
+impl DrawingBackend for PngBackend {
+    fn draw_pixel(&mut self, x: u64, y: u64, color: Rgba<u8>) {
+        self.image.set_pixel(x, y, color);
+    }
+
+    fn present(&self, path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+        self.image.upscale(self.scale).export_image().save(path)?;
+        Ok(())
+    }
+}
+
+/// Vector output: one `<rect>` per colored cell, merging horizontal runs of
+/// identical color into a single wide rect to keep file size reasonable.
+struct SvgBackend {
+    width: u64,
+    height: u64,
+    cell_size: u64,
+    pixels: Vec<Rgba<u8>>,
+}
+
+impl SvgBackend {
+    fn new(width: u64, height: u64, cell_size: u64) -> Self {
+        Self {
+            width,
+            height,
+            cell_size,
+            pixels: vec![Rgba([255, 255, 255, 255]); (width * height) as usize],
+        }
+    }
+}
+
+impl DrawingBackend for SvgBackend {
+    fn draw_pixel(&mut self, x: u64, y: u64, color: Rgba<u8>) {
+        if x < self.width && y < self.height {
+            self.pixels[(y * self.width + x) as usize] = color;
+        }
+    }
+
+    fn present(&self, path: &PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+        let px_width = self.width * self.cell_size;
+        let px_height = self.height * self.cell_size;
+
+        let mut svg = format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">\n",
+            px_width, px_height, px_width, px_height,
+        );
+
+        for y in 0..self.height {
+            let mut x = 0;
+            while x < self.width {
+                let color = self.pixels[(y * self.width + x) as usize];
+                let mut run_end = x + 1;
+                while run_end < self.width && self.pixels[(y * self.width + run_end) as usize] == color {
+                    run_end += 1;
+                }
+                let run_width = (run_end - x) * self.cell_size;
+                let alpha = color.0[3] as f32 / 255.0;
+
+                svg.push_str(&format!(
+                    "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#{:02x}{:02x}{:02x}\" fill-opacity=\"{:.3}\"/>\n",
+                    x * self.cell_size,
+                    y * self.cell_size,
+                    run_width,
+                    self.cell_size,
+                    color.0[0],
+                    color.0[1],
+                    color.0[2],
+                    alpha,
+                ));
+
+                x = run_end;
+            }
+        }
+
+        svg.push_str("</svg>\n");
+        std::fs::write(path, svg)?;
+        Ok(())
+    }
+}
+
 /// Derive deterministic walk and step parameters from private key
 /// Uses bytes 0-3 for walks (range 3-10) and bytes 4-7 for steps (range 100-300)
 fn derive_parameters(pk: &[u8; 32]) -> (u64, u64) {
@@ -426,6 +888,38 @@ struct Cli {
     /// Offset of corner stamps from edges (default: 0)
     #[arg(long, default_value = "0")]
     stamp_offset: u64,
+
+    /// Fill mode for the left half of the canvas before mirroring
+    #[arg(long, value_enum, default_value = "walk")]
+    mode: FillMode,
+
+    /// Number of fractal-turbulence octaves (only used by --mode turbulence)
+    #[arg(long, default_value = "4")]
+    octaves: u32,
+
+    /// Normalized turbulence cutoff in [0,1]; above it the foreground pixel is set
+    #[arg(long, default_value = "0.55")]
+    turbulence_threshold: f32,
+
+    /// Alpha (0-255) each walk stroke deposits; repeated visits accumulate toward full saturation
+    #[arg(long, default_value = "64")]
+    ink_alpha: u8,
+
+    /// Stroke color for pixels with the fewest visits (--mode walk), interpolated in CIE L*a*b*
+    #[arg(long, default_value = "255,217,102")]
+    color_start: RgbX,
+
+    /// Stroke color for pixels with the most visits (--mode walk), interpolated in CIE L*a*b*
+    #[arg(long, default_value = "204,0,102")]
+    color_end: RgbX,
+
+    /// Output format: raster PNG or resolution-independent SVG
+    #[arg(long, value_enum, default_value = "png")]
+    format: OutputFormat,
+
+    /// PNG: pixel upscale factor. SVG: cell size in output units.
+    #[arg(long, default_value = "8")]
+    scale: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -448,8 +942,8 @@ impl FromStr for RgbX {
 }
 
 impl RgbX {
-    fn to_rgb(&self) -> Rgb<u8> {
-        Rgb([self.0, self.1, self.2])
+    fn to_rgba(&self, alpha: u8) -> Rgba<u8> {
+        Rgba([self.0, self.1, self.2, alpha])
     }
 }
 
@@ -489,18 +983,30 @@ fn main() {
         }
     }
 
-    let pixel = cli.color.to_rgb();
-    let background = cli.background.to_rgb();
+    let pixel = cli.color.to_rgba(255);
+    let background = cli.background.to_rgba(255);
 
     // Create artist with private key as seed
     let mut artist = Artist::with_image(
         private_key,
         pixel,
+        cli.ink_alpha,
         PixelImage::new(64, 64, Some(background)),
     );
 
     // Generate Rorschach pattern
-    artist.draw_random(steps, walks);
+    match cli.mode {
+        FillMode::Walk => artist.draw_random(
+            steps,
+            walks,
+            cli.color_start.to_rgba(255),
+            cli.color_end.to_rgba(255),
+        ),
+        FillMode::Turbulence => {
+            artist.draw_turbulence(cli.octaves, cli.turbulence_threshold, background)
+        }
+        FillMode::Hilbert => artist.draw_hilbert(steps, walks, background),
+    }
 
     // Add private key stamp (optional)
     if !cli.no_stamp {
@@ -509,6 +1015,25 @@ fn main() {
 
     let image = &artist.drawyer.image;
 
-    // Upscale and save
-    image.upscale(8).export_image().save(cli.output).unwrap();
+    // Route the generated pixels through whichever backend was selected and save
+    match cli.format {
+        OutputFormat::Png => {
+            let mut backend = PngBackend::new(image.width, image.height, cli.scale);
+            for y in 0..image.height {
+                for x in 0..image.width {
+                    backend.draw_pixel(x, y, *image.get_pixel(x, y).unwrap());
+                }
+            }
+            backend.present(&cli.output).unwrap();
+        }
+        OutputFormat::Svg => {
+            let mut backend = SvgBackend::new(image.width, image.height, cli.scale);
+            for y in 0..image.height {
+                for x in 0..image.width {
+                    backend.draw_pixel(x, y, *image.get_pixel(x, y).unwrap());
+                }
+            }
+            backend.present(&cli.output).unwrap();
+        }
+    }
 }